@@ -0,0 +1,12 @@
+//! The hash map and set backing an `identified_vec`, from `std` if the `std`
+//! feature is enabled, else from `hashbrown`.
+
+#[cfg(all(feature = "std", feature = "serde"))]
+pub use std::collections::HashSet;
+#[cfg(feature = "std")]
+pub use std::collections::{hash_map::RandomState, HashMap};
+
+#[cfg(all(not(feature = "std"), feature = "serde"))]
+pub use hashbrown::HashSet;
+#[cfg(not(feature = "std"))]
+pub use hashbrown::{DefaultHashBuilder as RandomState, HashMap};
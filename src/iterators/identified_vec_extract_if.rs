@@ -0,0 +1,130 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::mem::{swap, take};
+
+use crate::collections::HashMap;
+use crate::{ElementMut, IdentifiedVec};
+
+/// An iterator which removes and yields, in order, the elements of an
+/// `IdentifiedVec` matching a predicate.
+///
+/// The storage of the `identified_vec` is moved into the iterator when it is
+/// created, compacted in a single pass over its order and moved back when the
+/// iterator is dropped. If the iterator is dropped before being exhausted the
+/// remaining elements are kept, if it is leaked, e.g. using `mem::forget`,
+/// the `identified_vec` is left empty and all its elements are leaked.
+pub struct IdentifiedVecExtractIf<'a, I, E, F, S, P>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+    P: FnMut(&mut E) -> bool,
+{
+    identified_vec: &'a mut IdentifiedVec<I, E, F, S>,
+    order: Vec<I>,
+    indices: HashMap<I, usize, S>,
+    elements: HashMap<I, E, S>,
+    /// Position of the next id to visit.
+    read: usize,
+    /// Position of the next kept id.
+    write: usize,
+    predicate: P,
+}
+
+impl<'a, I, E, F, S, P> IdentifiedVecExtractIf<'a, I, E, F, S, P>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+    P: FnMut(&mut E) -> bool,
+{
+    pub fn new(identified_vec: &'a mut IdentifiedVec<I, E, F, S>, predicate: P) -> Self
+    where
+        S: Default,
+    {
+        Self {
+            order: take(&mut identified_vec.order),
+            indices: take(&mut identified_vec.indices),
+            elements: take(&mut identified_vec.elements),
+            identified_vec,
+            read: 0,
+            write: 0,
+            predicate,
+        }
+    }
+
+    /// Moves the id at `self.read` into the gap left by removed elements.
+    #[inline]
+    fn keep_current(&mut self) {
+        if self.write != self.read {
+            self.order.swap(self.write, self.read);
+            *self
+                .indices
+                .get_mut(&self.order[self.write])
+                .expect("Index of existing id") = self.write;
+        }
+        self.read += 1;
+        self.write += 1;
+    }
+}
+
+impl<I, E, F, S, P> Iterator for IdentifiedVecExtractIf<'_, I, E, F, S, P>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+    P: FnMut(&mut E) -> bool,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.read < self.order.len() {
+            let id = &self.order[self.read];
+            let element = self.elements.get_mut(id).expect("Element for existing id");
+            let id_of_element = &self.identified_vec._id_of_element;
+            let extract = (self.predicate)(&mut ElementMut::new(element, id, id_of_element));
+            if extract {
+                self.indices.remove(id);
+                let element = self.elements.remove(id).expect("Element for existing id");
+                self.read += 1;
+                return Some(element);
+            }
+            self.keep_current();
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.order.len() - self.read))
+    }
+}
+
+impl<I, E, F, S, P> FusedIterator for IdentifiedVecExtractIf<'_, I, E, F, S, P>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+    P: FnMut(&mut E) -> bool,
+{
+}
+
+impl<I, E, F, S, P> Drop for IdentifiedVecExtractIf<'_, I, E, F, S, P>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+    P: FnMut(&mut E) -> bool,
+{
+    fn drop(&mut self) {
+        while self.read < self.order.len() {
+            self.keep_current();
+        }
+        self.order.truncate(self.write);
+        let vec = &mut *self.identified_vec;
+        swap(&mut vec.order, &mut self.order);
+        swap(&mut vec.indices, &mut self.indices);
+        swap(&mut vec.elements, &mut self.elements);
+    }
+}
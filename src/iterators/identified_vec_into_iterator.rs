@@ -1,36 +1,80 @@
-use std::fmt::Debug;
-use std::hash::Hash;
+use crate::collections::{HashMap, RandomState};
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
 
-use crate::{IdentifiedVec, IsIdentifiedVec};
+use crate::IdentifiedVec;
 
 /// An owning iterator over the items of an `IdentifiedVec`.
-pub struct IdentifiedVecIntoIterator<I, E>
+///
+/// Drains the elements in order, each call to `next` or `next_back` is O(1),
+/// making a full iteration O(n).
+pub struct IdentifiedVecIntoIterator<I, E, S = RandomState>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
-    identified_vec: IdentifiedVec<I, E>,
+    order: alloc::vec::IntoIter<I>,
+    elements: HashMap<I, E, S>,
 }
 
-impl<I, E> IdentifiedVecIntoIterator<I, E>
+impl<I, E, S> IdentifiedVecIntoIterator<I, E, S>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
-    pub fn new(identified_vec: IdentifiedVec<I, E>) -> Self {
-        Self { identified_vec }
+    pub fn new<F>(identified_vec: IdentifiedVec<I, E, F, S>) -> Self
+    where
+        F: Fn(&E) -> I,
+    {
+        Self {
+            order: identified_vec.order.into_iter(),
+            elements: identified_vec.elements,
+        }
+    }
+
+    #[inline]
+    fn take(&mut self, id: I) -> E {
+        self.elements.remove(&id).expect("Element for existing id")
     }
 }
 
-impl<I, E> Iterator for IdentifiedVecIntoIterator<I, E>
+impl<I, E, S> Iterator for IdentifiedVecIntoIterator<I, E, S>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
     type Item = E;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.identified_vec.len() == 0 {
-            return None;
-        }
-        let result = self.identified_vec.remove_at(0);
-        Some(result)
+        self.order.next().map(|id| self.take(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<I, E, S> DoubleEndedIterator for IdentifiedVecIntoIterator<I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.order.next_back().map(|id| self.take(id))
     }
 }
+
+impl<I, E, S> ExactSizeIterator for IdentifiedVecIntoIterator<I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+}
+
+impl<I, E, S> FusedIterator for IdentifiedVecIntoIterator<I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+}
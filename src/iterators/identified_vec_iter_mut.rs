@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+
+use crate::{ElementMut, IdentifiedVec};
+
+/// An iterator over mutable references to the items of an `IdentifiedVec`.
+///
+/// Yields `ElementMut` guards, which panic on drop if the id of the element
+/// has been mutated.
+pub struct IdentifiedVecIterMut<'a, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    order: core::slice::Iter<'a, I>,
+    elements: alloc::vec::IntoIter<Option<&'a mut E>>,
+    id_of_element: &'a dyn Fn(&E) -> I,
+}
+
+impl<'a, I, E> IdentifiedVecIterMut<'a, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    pub fn new<F, S>(identified_vec: &'a mut IdentifiedVec<I, E, F, S>) -> Self
+    where
+        F: Fn(&E) -> I,
+        S: BuildHasher,
+    {
+        let mut elements = Vec::<Option<&'a mut E>>::new();
+        elements.resize_with(identified_vec.order.len(), || None);
+        for (id, element) in identified_vec.elements.iter_mut() {
+            elements[identified_vec.indices[id]] = Some(element);
+        }
+        Self {
+            order: identified_vec.order.iter(),
+            elements: elements.into_iter(),
+            id_of_element: &identified_vec._id_of_element,
+        }
+    }
+
+    #[inline]
+    fn element(&self, id: &'a I, element: Option<&'a mut E>) -> ElementMut<'a, I, E> {
+        ElementMut::new(
+            element.expect("Element for existing id"),
+            id,
+            self.id_of_element,
+        )
+    }
+}
+
+impl<'a, I, E> Iterator for IdentifiedVecIterMut<'a, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    type Item = ElementMut<'a, I, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.order.next()?;
+        let element = self.elements.next().expect("Element for existing id");
+        Some(self.element(id, element))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<I, E> DoubleEndedIterator for IdentifiedVecIterMut<'_, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let id = self.order.next_back()?;
+        let element = self.elements.next_back().expect("Element for existing id");
+        Some(self.element(id, element))
+    }
+}
+
+impl<I, E> ExactSizeIterator for IdentifiedVecIterMut<'_, I, E> where I: Eq + Hash + Clone + Debug {}
+
+impl<I, E> FusedIterator for IdentifiedVecIterMut<'_, I, E> where I: Eq + Hash + Clone + Debug {}
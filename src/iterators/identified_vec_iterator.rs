@@ -1,42 +1,94 @@
-use std::fmt::Debug;
-use std::hash::Hash;
+use crate::collections::{HashMap, RandomState};
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
 
-use crate::{IdentifiedVec, IsIdentifiedVec};
+use crate::IdentifiedVec;
 
 /// An iterator over the items of an `IdentifiedVec`.
-pub struct IdentifiedVecIterator<'a, I, E>
+pub struct IdentifiedVecIterator<'a, I, E, S = RandomState>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
-    identified_vec: &'a IdentifiedVec<I, E>,
-    index: usize,
+    order: core::slice::Iter<'a, I>,
+    elements: &'a HashMap<I, E, S>,
 }
 
-impl<'a, I, E> IdentifiedVecIterator<'a, I, E>
+impl<'a, I, E, S> IdentifiedVecIterator<'a, I, E, S>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
-    pub fn new(identified_vec: &'a IdentifiedVec<I, E>) -> Self {
+    pub fn new<F>(identified_vec: &'a IdentifiedVec<I, E, F, S>) -> Self
+    where
+        F: Fn(&E) -> I,
+    {
         Self {
-            identified_vec,
-            index: 0,
+            order: identified_vec.order.iter(),
+            elements: &identified_vec.elements,
+        }
+    }
+
+    #[inline]
+    fn element(&self, id: &I) -> &'a E {
+        self.elements.get(id).expect("Element for existing id")
+    }
+}
+
+impl<I, E, S> Clone for IdentifiedVecIterator<'_, I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+    fn clone(&self) -> Self {
+        Self {
+            order: self.order.clone(),
+            elements: self.elements,
         }
     }
 }
 
-impl<'a, I, E> Iterator for IdentifiedVecIterator<'a, I, E>
+impl<'a, I, E, S> Iterator for IdentifiedVecIterator<'a, I, E, S>
 where
     I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
 {
     type Item = &'a E;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.identified_vec.len() {
-            let id = Some(&self.identified_vec.order[self.index]).unwrap();
-            self.index += 1;
-            return self.identified_vec.get(id);
-        } else {
-            None
-        }
+        self.order.next().map(|id| self.element(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.order.nth(n).map(|id| self.element(id))
     }
 }
+
+impl<I, E, S> DoubleEndedIterator for IdentifiedVecIterator<'_, I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.order.next_back().map(|id| self.element(id))
+    }
+}
+
+impl<I, E, S> ExactSizeIterator for IdentifiedVecIterator<'_, I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+}
+
+impl<I, E, S> FusedIterator for IdentifiedVecIterator<'_, I, E, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    S: BuildHasher,
+{
+}
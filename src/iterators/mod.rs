@@ -1,2 +1,4 @@
+pub mod identified_vec_extract_if;
 pub mod identified_vec_into_iterator;
+pub mod identified_vec_iter_mut;
 pub mod identified_vec_iterator;
@@ -116,8 +116,25 @@
 //! // closure which plucks out an ID from an element.
 //! let numbers = IdentifiedVec::<u32, u32>::new_identifying_element(|e| *e);
 //! ```
+//!
+//! The crate is `no_std`, requiring only an allocator: disable the default
+//! `std` feature and enable the `alloc` feature to back the `IdentifiedVec`
+//! by `hashbrown` instead of `std::collections::HashMap`.
+
+#![no_std]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("identified_vec requires either the `std` or the `alloc` feature");
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod collections;
 
 mod iterators;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod vec;
 mod vec_of;
 
@@ -136,5 +153,12 @@ pub mod identified_vec_of {
     pub use crate::vec_of::*;
 }
 
+#[doc(hidden)]
+pub mod __private {
+    //! Items used by the expansion of `newtype_identified_vec!`, not public API.
+    pub use crate::collections::RandomState;
+    pub use alloc::vec::Vec;
+}
+
 pub use crate::identified_vec::*;
 pub use crate::identified_vec_of::*;
@@ -0,0 +1,73 @@
+//! (De)serializes a `Deduplicated<IdentifiedVecOf>` as a sequence of
+//! elements, like the `Serialize` and `Deserialize` impls of
+//! `IdentifiedVecOf`, but keeps the first of any elements with the same id
+//! and collects a warning for every other one instead of failing when
+//! deserializing.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::serde::Deduplicated;
+//! use identified_vec::{IdentifiedVecOf, IdentifiedVecOfSerdeFailure, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "identified_vec::serde::collect_warnings")]
+//!     ports: Deduplicated<IdentifiedVecOf<u16>>,
+//! }
+//!
+//! let settings = serde_json::from_str::<Settings>(r#"{"ports":[80,443,80]}"#).unwrap();
+//! assert_eq!(settings.ports.value.ids(), [80, 443]);
+//! assert_eq!(
+//!     settings.ports.warnings,
+//!     [IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(2)]
+//! );
+//! assert_eq!(serde_json::to_string(&settings).unwrap(), r#"{"ports":[80,443]}"#);
+//! ```
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::sequence::deserialize_selecting;
+use super::Deduplicated;
+use crate::{
+    ConflictResolutionChoice, Identifiable, IdentifiedVec, IdentifiedVecOf,
+    IdentifiedVecOfSerdeFailure,
+};
+
+/// Serializes the value of `deduplicated` as a sequence of elements.
+pub fn serialize<I, E, F, H, S>(
+    deduplicated: &Deduplicated<IdentifiedVec<I, E, F, H>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    deduplicated.value.serialize(serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from a sequence of elements, keeping the
+/// first of any elements with the same id and a warning for every other one.
+pub fn deserialize<'de, E, H, D>(
+    deserializer: D,
+) -> Result<Deduplicated<IdentifiedVecOf<E, H>>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    let mut warnings = Vec::new();
+    let value = deserialize_selecting(deserializer, |conflict| {
+        warnings.push(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(
+            conflict.index,
+        ));
+        ConflictResolutionChoice::ChooseFirst
+    })?;
+    Ok(Deduplicated { value, warnings })
+}
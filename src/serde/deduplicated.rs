@@ -0,0 +1,14 @@
+use crate::IdentifiedVecOfSerdeFailure;
+use alloc::vec::Vec;
+
+/// A value deserialized using `collect_warnings`, together with a warning for
+/// every duplicate element which was dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deduplicated<T> {
+    /// The deserialized value, keeping the first of any elements with the
+    /// same id.
+    pub value: T,
+
+    /// A `DuplicateElementsAtIndex` for every dropped element, in input order.
+    pub warnings: Vec<IdentifiedVecOfSerdeFailure>,
+}
@@ -0,0 +1,149 @@
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use super::{collect_warnings, keep_first, keep_last, merge, Deduplicated, MergeDuplicate};
+use crate::{Identifiable, IdentifiedVec, IdentifiedVecOf};
+
+/// Like `keep_first`, for use with `#[serde_as(as = "KeepFirst")]`, or
+/// composed with other `serde_with` adapters.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::serde::KeepFirst;
+/// use identified_vec::{IdentifiedVecOf, IsIdentifiedVec};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde(with = "serde_with::As::<Option<KeepFirst>>")]
+///     ports: Option<IdentifiedVecOf<u16>>,
+/// }
+///
+/// let settings = serde_json::from_str::<Settings>(r#"{"ports":[80,443,80]}"#).unwrap();
+/// assert_eq!(settings.ports.unwrap().ids(), [80, 443]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeepFirst;
+
+/// Like `keep_last`, for use with `#[serde_as(as = "KeepLast")]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeepLast;
+
+/// Like `merge`, for use with `#[serde_as(as = "Merge")]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Merge;
+
+/// Like `collect_warnings`, for use with `#[serde_as(as = "CollectWarnings")]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CollectWarnings;
+
+impl<I, E, F, H> SerializeAs<IdentifiedVec<I, E, F, H>> for KeepFirst
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize_as<S: Serializer>(
+        source: &IdentifiedVec<I, E, F, H>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        keep_first::serialize(source, serializer)
+    }
+}
+
+impl<'de, E, H> DeserializeAs<'de, IdentifiedVecOf<E, H>> for KeepFirst
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IdentifiedVecOf<E, H>, D::Error> {
+        keep_first::deserialize(deserializer)
+    }
+}
+
+impl<I, E, F, H> SerializeAs<IdentifiedVec<I, E, F, H>> for KeepLast
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize_as<S: Serializer>(
+        source: &IdentifiedVec<I, E, F, H>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        keep_last::serialize(source, serializer)
+    }
+}
+
+impl<'de, E, H> DeserializeAs<'de, IdentifiedVecOf<E, H>> for KeepLast
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IdentifiedVecOf<E, H>, D::Error> {
+        keep_last::deserialize(deserializer)
+    }
+}
+
+impl<I, E, F, H> SerializeAs<IdentifiedVec<I, E, F, H>> for Merge
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize_as<S: Serializer>(
+        source: &IdentifiedVec<I, E, F, H>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        merge::serialize(source, serializer)
+    }
+}
+
+impl<'de, E, H> DeserializeAs<'de, IdentifiedVecOf<E, H>> for Merge
+where
+    E: MergeDuplicate + Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<IdentifiedVecOf<E, H>, D::Error> {
+        merge::deserialize(deserializer)
+    }
+}
+
+impl<I, E, F, H> SerializeAs<Deduplicated<IdentifiedVec<I, E, F, H>>> for CollectWarnings
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize_as<S: Serializer>(
+        source: &Deduplicated<IdentifiedVec<I, E, F, H>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        collect_warnings::serialize(source, serializer)
+    }
+}
+
+impl<'de, E, H> DeserializeAs<'de, Deduplicated<IdentifiedVecOf<E, H>>> for CollectWarnings
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Deduplicated<IdentifiedVecOf<E, H>>, D::Error> {
+        collect_warnings::deserialize(deserializer)
+    }
+}
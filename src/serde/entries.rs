@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use super::keys::KeySeed;
+use crate::{Identifiable, IdentifiedVecOfSerdeFailure};
+
+/// A `DeserializeSeed` of the entries of a map, in the order they are found,
+/// with keys represented using `C`.
+pub(crate) struct EntriesSeed<C, I, E>(PhantomData<Deserialized<C, I, E>>);
+
+/// The key representation and types deserialized, `fn` makes the seed `Send`
+/// and `Sync` regardless of them.
+type Deserialized<C, I, E> = fn() -> (C, I, E);
+
+impl<C, I, E> EntriesSeed<C, I, E> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, C, I, E> DeserializeSeed<'de> for EntriesSeed<C, I, E>
+where
+    KeySeed<C, I>: DeserializeSeed<'de, Value = I>,
+    E: Deserialize<'de>,
+{
+    type Value = Vec<(I, E)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, C, I, E> Visitor<'de> for EntriesSeed<C, I, E>
+where
+    KeySeed<C, I>: DeserializeSeed<'de, Value = I>,
+    E: Deserialize<'de>,
+{
+    type Value = Vec<(I, E)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of elements keyed by their ids")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(key) = map.next_key_seed(KeySeed::<C, I>::new())? {
+            entries.push((key, map.next_value()?));
+        }
+        Ok(entries)
+    }
+}
+
+/// Returns the elements of `entries` after passing each of them, together
+/// with its key, to `inject`, failing with
+/// `IdentifiedVecOfSerdeFailure::KeyMismatchAtIndex` if the id of an element
+/// differs from its key.
+pub(crate) fn elements_matching_keys<E, J, Er>(
+    entries: Vec<(E::ID, E)>,
+    inject: J,
+) -> Result<Vec<E>, Er>
+where
+    E: Identifiable,
+    J: Fn(&mut E, &E::ID),
+    Er: de::Error,
+{
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, (key, mut element))| {
+            inject(&mut element, &key);
+            if element.id() == key {
+                Ok(element)
+            } else {
+                Err(de::Error::custom(
+                    IdentifiedVecOfSerdeFailure::KeyMismatchAtIndex(index),
+                ))
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,46 @@
+use crate::Identifiable;
+
+/// Elements which omit their id when serialized, e.g. using
+/// `#[serde(skip)]`, and have it injected from the key under which they are
+/// found when deserialized using `ordered_map::injecting_id` or
+/// `ordered_map_display::injecting_id`.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::serde::InjectId;
+/// use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(skip)]
+///     id: u16,
+///     name: String,
+/// }
+/// impl Identifiable for User {
+///     type ID = u16;
+///     fn id(&self) -> Self::ID {
+///         self.id
+///     }
+/// }
+/// impl InjectId for User {
+///     fn inject_id(&mut self, id: Self::ID) {
+///         self.id = id
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Team {
+///     #[serde(with = "identified_vec::serde::ordered_map::injecting_id")]
+///     users: IdentifiedVecOf<User>,
+/// }
+///
+/// let json = r#"{"users":{"2":{"name":"Blob, Jr."},"1":{"name":"Blob"}}}"#;
+/// let team = serde_json::from_str::<Team>(json).unwrap();
+/// assert_eq!(team.users.ids(), [2, 1]);
+/// assert_eq!(serde_json::to_string(&team).unwrap(), json);
+/// ```
+pub trait InjectId: Identifiable {
+    /// Sets the id of this element to `id`, the key it was deserialized under.
+    fn inject_id(&mut self, id: Self::ID);
+}
@@ -0,0 +1,37 @@
+//! (De)serializes an `IdentifiedVecOf` as a sequence of elements, like its
+//! `Serialize` and `Deserialize` impls, but keeps the first of any elements
+//! with the same id instead of failing when deserializing.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "identified_vec::serde::keep_first")]
+//!     ports: IdentifiedVecOf<u16>,
+//! }
+//!
+//! let settings = serde_json::from_str::<Settings>(r#"{"ports":[80,443,80]}"#).unwrap();
+//! assert_eq!(settings.ports.ids(), [80, 443]);
+//! ```
+use core::hash::BuildHasher;
+
+use serde::{Deserialize, Deserializer};
+
+use super::sequence::deserialize_selecting;
+use crate::{ConflictResolutionChoice, Identifiable, IdentifiedVecOf};
+
+pub use super::sequence::serialize;
+
+/// Deserializes an `IdentifiedVecOf` from a sequence of elements, keeping the
+/// first of any elements with the same id.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_selecting(deserializer, |_| ConflictResolutionChoice::ChooseFirst)
+}
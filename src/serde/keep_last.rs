@@ -0,0 +1,52 @@
+//! (De)serializes an `IdentifiedVecOf` as a sequence of elements, like its
+//! `Serialize` and `Deserialize` impls, but keeps the last of any elements
+//! with the same id, at the position of the first, instead of failing when
+//! deserializing.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Entry {
+//!     key: String,
+//!     value: u8,
+//! }
+//! impl Identifiable for Entry {
+//!     type ID = String;
+//!     fn id(&self) -> Self::ID {
+//!         self.key.clone()
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "identified_vec::serde::keep_last")]
+//!     entries: IdentifiedVecOf<Entry>,
+//! }
+//!
+//! let json = r#"{"entries":[{"key":"a","value":1},{"key":"b","value":2},{"key":"a","value":3}]}"#;
+//! let config = serde_json::from_str::<Config>(json).unwrap();
+//! assert_eq!(config.entries.ids(), ["a", "b"]);
+//! assert_eq!(config.entries.get(&"a".to_string()).unwrap().value, 3);
+//! ```
+use core::hash::BuildHasher;
+
+use serde::{Deserialize, Deserializer};
+
+use super::sequence::deserialize_selecting;
+use crate::{ConflictResolutionChoice, Identifiable, IdentifiedVecOf};
+
+pub use super::sequence::serialize;
+
+/// Deserializes an `IdentifiedVecOf` from a sequence of elements, keeping the
+/// last of any elements with the same id.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_selecting(deserializer, |_| ConflictResolutionChoice::ChooseLast)
+}
@@ -0,0 +1,74 @@
+use alloc::string::String;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{self, DeserializeSeed};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Represents ids as keys using their `Serialize` and `Deserialize` impls.
+pub(crate) struct SerdeKeys;
+
+/// Represents ids as string keys using their `Display` and `FromStr` impls.
+pub(crate) struct DisplayKeys;
+
+/// An id serialized as a key using the representation `C`.
+pub(crate) struct Key<'a, C, I>(&'a I, PhantomData<C>);
+
+impl<'a, C, I> Key<'a, C, I> {
+    pub(crate) fn new(id: &'a I) -> Self {
+        Self(id, PhantomData)
+    }
+}
+
+impl<'a, I> Serialize for Key<'a, SerdeKeys, I>
+where
+    I: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'a, I> Serialize for Key<'a, DisplayKeys, I>
+where
+    I: Display,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+/// A `DeserializeSeed` of an id represented as a key using `C`.
+pub(crate) struct KeySeed<C, I>(PhantomData<fn() -> (C, I)>);
+
+impl<C, I> KeySeed<C, I> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, I> DeserializeSeed<'de> for KeySeed<SerdeKeys, I>
+where
+    I: Deserialize<'de>,
+{
+    type Value = I;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<I, D::Error> {
+        I::deserialize(deserializer)
+    }
+}
+
+impl<'de, I> DeserializeSeed<'de> for KeySeed<DisplayKeys, I>
+where
+    I: FromStr,
+    I::Err: Display,
+{
+    type Value = I;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<I, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
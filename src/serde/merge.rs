@@ -0,0 +1,80 @@
+//! (De)serializes an `IdentifiedVecOf` as a sequence of elements, like its
+//! `Serialize` and `Deserialize` impls, but merges any elements with the same
+//! id using `MergeDuplicate`, at the position of the first, instead of
+//! failing when deserializing.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::serde::MergeDuplicate;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Balance {
+//!     account: String,
+//!     amount: i32,
+//! }
+//! impl Identifiable for Balance {
+//!     type ID = String;
+//!     fn id(&self) -> Self::ID {
+//!         self.account.clone()
+//!     }
+//! }
+//! impl MergeDuplicate for Balance {
+//!     fn merge_duplicate(self, duplicate: Self) -> Self {
+//!         Self {
+//!             amount: self.amount + duplicate.amount,
+//!             ..self
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Ledger {
+//!     #[serde(with = "identified_vec::serde::merge")]
+//!     balances: IdentifiedVecOf<Balance>,
+//! }
+//!
+//! let json = r#"{"balances":[{"account":"a","amount":1},{"account":"b","amount":2},{"account":"a","amount":3}]}"#;
+//! let ledger = serde_json::from_str::<Ledger>(json).unwrap();
+//! assert_eq!(ledger.balances.ids(), ["a", "b"]);
+//! assert_eq!(ledger.balances.get(&"a".to_string()).unwrap().amount, 4);
+//! ```
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+
+use serde::{de, Deserialize, Deserializer};
+
+use super::MergeDuplicate;
+use crate::{Error, IdentifiedVecOf, IsIdentifiedVec};
+
+pub use super::sequence::serialize;
+
+/// Deserializes an `IdentifiedVecOf` from a sequence of elements, merging any
+/// elements with the same id.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: MergeDuplicate + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    let elements = Vec::<E>::deserialize(deserializer)?;
+    IdentifiedVecOf::try_from_iter_merge_unique_ids_with(
+        elements,
+        E::id as fn(&E) -> E::ID,
+        |conflict| {
+            let expected = conflict.id.clone();
+            let merged = conflict.existing.merge_duplicate(conflict.new);
+            let found = merged.id();
+            if found == expected {
+                Ok(merged)
+            } else {
+                Err(de::Error::custom(Error::IdMismatch {
+                    expected,
+                    found,
+                    index: conflict.existing_index,
+                }))
+            }
+        },
+    )
+}
@@ -0,0 +1,9 @@
+use crate::Identifiable;
+
+/// Elements which can merge a duplicate, an element with the same id, into
+/// themselves, used by `merge` when deserializing.
+pub trait MergeDuplicate: Identifiable {
+    /// Merges `duplicate`, which was found after `self`, into `self`, the
+    /// result must have the same id.
+    fn merge_duplicate(self, duplicate: Self) -> Self;
+}
@@ -0,0 +1,40 @@
+//! Serde adapters, for use with `#[serde(with = "...")]`, representing an
+//! `IdentifiedVecOf` otherwise than as the sequence of its elements used by
+//! its `Serialize` and `Deserialize` impls, or handling duplicate elements
+//! otherwise than by failing.
+//!
+//! - `ordered_map`: `{"<id>": {...}, ...}`, in the order of the elements.
+//! - `normalized`: `{"ids": [...], "entities": {"<id>": {...}, ...}}`.
+//!
+//! Both use the `Serialize` and `Deserialize` impls of the ids as keys, the
+//! `*_display` variants use their `Display` and `FromStr` impls instead.
+//!
+//! - `keep_first`, `keep_last`: keep one of any elements with the same id.
+//! - `merge`: merge any elements with the same id using `MergeDuplicate`.
+//! - `collect_warnings`: keep the first of any elements with the same id and
+//!   a warning for every other one, in a `Deduplicated`.
+//!
+//! With the `"serde_with"` feature, `KeepFirst`, `KeepLast`, `Merge` and
+//! `CollectWarnings` offer the same policies for use with `#[serde_as]`.
+pub mod collect_warnings;
+mod deduplicated;
+#[cfg(feature = "serde_with")]
+mod duplicate_policies;
+mod entries;
+mod inject_id;
+pub mod keep_first;
+pub mod keep_last;
+mod keys;
+pub mod merge;
+mod merge_duplicate;
+pub mod normalized;
+pub mod normalized_display;
+pub mod ordered_map;
+pub mod ordered_map_display;
+mod sequence;
+
+pub use deduplicated::*;
+#[cfg(feature = "serde_with")]
+pub use duplicate_policies::*;
+pub use inject_id::*;
+pub use merge_duplicate::*;
@@ -0,0 +1,331 @@
+//! (De)serializes an `IdentifiedVecOf` in the normalized form
+//! `{"ids":[...],"entities":{...}}`, the ids in the order of the elements and
+//! a map from ids to elements, using the `Serialize` and `Deserialize` impls
+//! of the ids.
+//!
+//! Deserialization fails with `DuplicateElementsAtIndex` if an id is listed
+//! twice, or two entities have the same id, with `KeyMismatchAtIndex` if the
+//! id of an entity differs from its key, with `MissingEntityAtIndex` if an
+//! id has no entity and with `UnreferencedEntityAtIndex` if an entity is not
+//! listed in the ids.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     id: u16,
+//! }
+//! impl Identifiable for User {
+//!     type ID = u16;
+//!     fn id(&self) -> Self::ID {
+//!         self.id
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State {
+//!     #[serde(with = "identified_vec::serde::normalized")]
+//!     users: IdentifiedVecOf<User>,
+//! }
+//!
+//! let json = r#"{"users":{"ids":[2,1],"entities":{"2":{"id":2},"1":{"id":1}}}}"#;
+//! let state = serde_json::from_str::<State>(json).unwrap();
+//! assert_eq!(state.users.ids(), [2, 1]);
+//! assert_eq!(serde_json::to_string(&state).unwrap(), json);
+//! ```
+use crate::collections::{HashMap, HashSet};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::entries::{elements_matching_keys, EntriesSeed};
+use super::keys::{Key, KeySeed, SerdeKeys};
+use super::ordered_map;
+use crate::{
+    from_unique_elements, Identifiable, IdentifiedVec, IdentifiedVecOf, IdentifiedVecOfSerdeFailure,
+};
+
+/// Serializes `identified_vec` in the normalized form.
+pub fn serialize<I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug + Serialize,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    serialize_with_keys::<SerdeKeys, _, _, _, _, _>(identified_vec, serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from the normalized form.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    E::ID: Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_with_keys::<SerdeKeys, _, _, _>(deserializer)
+}
+
+const FIELDS: &[&str] = &["ids", "entities"];
+
+/// Serializes `identified_vec` in the normalized form, with ids represented
+/// using `C`.
+pub(crate) fn serialize_with_keys<C, I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    for<'a> Key<'a, C, I>: Serialize,
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    let mut normalized = serializer.serialize_struct("IdentifiedVec", FIELDS.len())?;
+    normalized.serialize_field("ids", &Ids::<C, _, _, _, _>(identified_vec, PhantomData))?;
+    normalized.serialize_field(
+        "entities",
+        &Entities::<C, _, _, _, _>(identified_vec, PhantomData),
+    )?;
+    normalized.end()
+}
+
+/// Deserializes an `IdentifiedVecOf` from the normalized form, with ids
+/// represented using `C`.
+pub(crate) fn deserialize_with_keys<'de, C, E, H, D>(
+    deserializer: D,
+) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    KeySeed<C, E::ID>: DeserializeSeed<'de, Value = E::ID>,
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(
+        "IdentifiedVec",
+        FIELDS,
+        NormalizedVisitor::<C, E, H>(PhantomData),
+    )
+}
+
+/// The ids of an `IdentifiedVec`, in order, represented using `C`.
+struct Ids<'a, C, I, E, F, H>(&'a IdentifiedVec<I, E, F, H>, PhantomData<C>)
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    H: BuildHasher;
+
+impl<'a, C, I, E, F, H> Serialize for Ids<'a, C, I, E, F, H>
+where
+    for<'k> Key<'k, C, I>: Serialize,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.order.iter().map(Key::<C, I>::new))
+    }
+}
+
+/// The elements of an `IdentifiedVec` as a map, with ids represented using `C`.
+struct Entities<'a, C, I, E, F, H>(&'a IdentifiedVec<I, E, F, H>, PhantomData<C>)
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    H: BuildHasher;
+
+impl<'a, C, I, E, F, H> Serialize for Entities<'a, C, I, E, F, H>
+where
+    for<'k> Key<'k, C, I>: Serialize,
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ordered_map::serialize_with_keys::<C, _, _, _, _, _>(self.0, serializer)
+    }
+}
+
+/// A sequence of ids represented using `C`.
+struct IdsSeed<C, I>(PhantomData<fn() -> (C, I)>);
+
+impl<'de, C, I> DeserializeSeed<'de> for IdsSeed<C, I>
+where
+    KeySeed<C, I>: DeserializeSeed<'de, Value = I>,
+{
+    type Value = Vec<I>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<I>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, C, I> Visitor<'de> for IdsSeed<C, I>
+where
+    KeySeed<C, I>: DeserializeSeed<'de, Value = I>,
+{
+    type Value = Vec<I>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of ids")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<I>, A::Error> {
+        let mut ids = Vec::new();
+        while let Some(id) = seq.next_element_seed(KeySeed::<C, I>::new())? {
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+}
+
+/// The fields of the normalized form.
+enum Field {
+    Ids,
+    Entities,
+    Ignored,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("`ids` or `entities`")
+    }
+
+    fn visit_u64<Er: de::Error>(self, value: u64) -> Result<Field, Er> {
+        Ok(match value {
+            0 => Field::Ids,
+            1 => Field::Entities,
+            _ => Field::Ignored,
+        })
+    }
+
+    fn visit_str<Er: de::Error>(self, value: &str) -> Result<Field, Er> {
+        Ok(match value {
+            "ids" => Field::Ids,
+            "entities" => Field::Entities,
+            _ => Field::Ignored,
+        })
+    }
+}
+
+struct NormalizedVisitor<C, E, H>(PhantomData<Deserialized<C, E, H>>);
+
+/// The key representation and types deserialized, `fn` makes the visitor
+/// `Send` and `Sync` regardless of them.
+type Deserialized<C, E, H> = fn() -> (C, E, H);
+
+impl<'de, C, E, H> Visitor<'de> for NormalizedVisitor<C, E, H>
+where
+    KeySeed<C, E::ID>: DeserializeSeed<'de, Value = E::ID>,
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    type Value = IdentifiedVecOf<E, H>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a struct with `ids` and `entities`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let ids = seq
+            .next_element_seed(IdsSeed::<C, E::ID>(PhantomData))?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let entities = seq
+            .next_element_seed(EntriesSeed::<C, E::ID, E>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        from_normalized(ids, entities)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut ids = None;
+        let mut entities = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Ids if ids.is_some() => return Err(de::Error::duplicate_field("ids")),
+                Field::Ids => ids = Some(map.next_value_seed(IdsSeed::<C, E::ID>(PhantomData))?),
+                Field::Entities if entities.is_some() => {
+                    return Err(de::Error::duplicate_field("entities"))
+                }
+                Field::Entities => {
+                    entities = Some(map.next_value_seed(EntriesSeed::<C, E::ID, E>::new())?)
+                }
+                Field::Ignored => _ = map.next_value::<IgnoredAny>()?,
+            }
+        }
+        from_normalized(
+            ids.ok_or_else(|| de::Error::missing_field("ids"))?,
+            entities.ok_or_else(|| de::Error::missing_field("entities"))?,
+        )
+    }
+}
+
+/// Creates an `IdentifiedVecOf` of the `entities`, ordered by `ids`.
+fn from_normalized<E, H, Er>(
+    ids: Vec<E::ID>,
+    entities: Vec<(E::ID, E)>,
+) -> Result<IdentifiedVecOf<E, H>, Er>
+where
+    E: Identifiable,
+    H: BuildHasher + Default,
+    Er: de::Error,
+{
+    let failure = |failure: IdentifiedVecOfSerdeFailure| Err(de::Error::custom(failure));
+
+    let mut listed = HashSet::<&E::ID>::with_capacity(ids.len());
+    if let Some(index) = ids.iter().position(|id| !listed.insert(id)) {
+        return failure(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(index));
+    }
+
+    let mut entities_by_id = HashMap::<E::ID, (usize, E)>::with_capacity(entities.len());
+    for (index, entity) in elements_matching_keys(entities, |_, _| {})?
+        .into_iter()
+        .enumerate()
+    {
+        if entities_by_id
+            .insert(entity.id(), (index, entity))
+            .is_some()
+        {
+            return failure(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(index));
+        }
+    }
+
+    let mut elements = Vec::with_capacity(ids.len());
+    for (index, id) in ids.iter().enumerate() {
+        match entities_by_id.remove(id) {
+            Some((_, element)) => elements.push(element),
+            None => return failure(IdentifiedVecOfSerdeFailure::MissingEntityAtIndex(index)),
+        }
+    }
+    if let Some(index) = entities_by_id.into_values().map(|(index, _)| index).min() {
+        return failure(IdentifiedVecOfSerdeFailure::UnreferencedEntityAtIndex(
+            index,
+        ));
+    }
+
+    from_unique_elements(elements, E::id as fn(&E) -> E::ID)
+}
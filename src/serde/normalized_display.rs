@@ -0,0 +1,68 @@
+//! Like `normalized`, but represents ids as strings using their `Display` and
+//! `FromStr` impls, for ids which cannot be map keys using their `Serialize`
+//! impl.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     id: u16,
+//! }
+//! impl Identifiable for User {
+//!     type ID = u16;
+//!     fn id(&self) -> Self::ID {
+//!         self.id
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct State {
+//!     #[serde(with = "identified_vec::serde::normalized_display")]
+//!     users: IdentifiedVecOf<User>,
+//! }
+//!
+//! let json = r#"{"users":{"ids":["2","1"],"entities":{"2":{"id":2},"1":{"id":1}}}}"#;
+//! let state = serde_json::from_str::<State>(json).unwrap();
+//! assert_eq!(state.users.ids(), [2, 1]);
+//! assert_eq!(serde_json::to_string(&state).unwrap(), json);
+//! ```
+use core::fmt::{Debug, Display};
+use core::hash::{BuildHasher, Hash};
+use core::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::keys::DisplayKeys;
+use super::normalized::{deserialize_with_keys, serialize_with_keys};
+use crate::{Identifiable, IdentifiedVec, IdentifiedVecOf};
+
+/// Serializes `identified_vec` in the normalized form, ids as strings.
+pub fn serialize<I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug + Display,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    serialize_with_keys::<DisplayKeys, _, _, _, _, _>(identified_vec, serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from the normalized form, ids as
+/// strings.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    E::ID: FromStr,
+    <E::ID as FromStr>::Err: Display,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_with_keys::<DisplayKeys, _, _, _>(deserializer)
+}
@@ -0,0 +1,140 @@
+//! (De)serializes an `IdentifiedVecOf` as a map from ids to elements, in the
+//! order of the elements, e.g. `{"1":{"id":1},"2":{"id":2}}` in JSON, using
+//! the `Serialize` and `Deserialize` impls of the ids as keys.
+//!
+//! Deserialization fails with `DuplicateElementsAtIndex` if two elements
+//! have the same id, and with `KeyMismatchAtIndex` if the id of an element
+//! differs from its key. Use `injecting_id` for elements which omit their
+//! id, see `InjectId`.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     id: u16,
+//! }
+//! impl Identifiable for User {
+//!     type ID = u16;
+//!     fn id(&self) -> Self::ID {
+//!         self.id
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Team {
+//!     #[serde(with = "identified_vec::serde::ordered_map")]
+//!     users: IdentifiedVecOf<User>,
+//! }
+//!
+//! let json = r#"{"users":{"2":{"id":2},"1":{"id":1}}}"#;
+//! let team = serde_json::from_str::<Team>(json).unwrap();
+//! assert_eq!(team.users.ids(), [2, 1]);
+//! assert_eq!(serde_json::to_string(&team).unwrap(), json);
+//! assert!(serde_json::from_str::<Team>(r#"{"users":{"2":{"id":1}}}"#).is_err());
+//! ```
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::entries::{elements_matching_keys, EntriesSeed};
+use super::keys::{Key, KeySeed, SerdeKeys};
+use crate::{from_unique_elements, Identifiable, IdentifiedVec, IdentifiedVecOf};
+
+/// Serializes `identified_vec` as a map from ids to elements.
+pub fn serialize<I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug + Serialize,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    serialize_with_keys::<SerdeKeys, _, _, _, _, _>(identified_vec, serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from a map from ids to elements.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    E::ID: Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_with_keys::<SerdeKeys, _, _, _, _>(deserializer, |_, _| {})
+}
+
+pub mod injecting_id {
+    //! Like `ordered_map`, but injects the key of every element into it
+    //! using `InjectId` when deserializing.
+    use core::hash::BuildHasher;
+
+    use serde::{Deserialize, Deserializer};
+
+    use super::super::keys::SerdeKeys;
+    use crate::serde::InjectId;
+    use crate::IdentifiedVecOf;
+
+    pub use super::serialize;
+
+    /// Deserializes an `IdentifiedVecOf` from a map from ids to elements,
+    /// injecting the keys into the elements.
+    pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+    where
+        E: InjectId + Deserialize<'de>,
+        E::ID: Deserialize<'de>,
+        H: BuildHasher + Default,
+        D: Deserializer<'de>,
+    {
+        super::deserialize_with_keys::<SerdeKeys, _, _, _, _>(
+            deserializer,
+            |element: &mut E, id| element.inject_id(id.clone()),
+        )
+    }
+}
+
+/// Serializes `identified_vec` as a map, with ids represented using `C`.
+pub(crate) fn serialize_with_keys<C, I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    for<'a> Key<'a, C, I>: Serialize,
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    serializer.collect_map(
+        identified_vec
+            .order
+            .iter()
+            .map(|id| (Key::<C, I>::new(id), &identified_vec.elements[id])),
+    )
+}
+
+/// Deserializes an `IdentifiedVecOf` from a map, with ids represented using
+/// `C`, passing every element and its key to `inject`.
+pub(crate) fn deserialize_with_keys<'de, C, E, H, D, J>(
+    deserializer: D,
+    inject: J,
+) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    KeySeed<C, E::ID>: DeserializeSeed<'de, Value = E::ID>,
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+    J: Fn(&mut E, &E::ID),
+{
+    let entries = EntriesSeed::<C, E::ID, E>::new().deserialize(deserializer)?;
+    let elements = elements_matching_keys(entries, inject)?;
+    from_unique_elements(elements, E::id as fn(&E) -> E::ID)
+}
@@ -0,0 +1,101 @@
+//! Like `ordered_map`, but represents ids as string keys using their
+//! `Display` and `FromStr` impls, for ids which cannot be map keys using
+//! their `Serialize` impl.
+//!
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//! impl Identifiable for Point {
+//!     type ID = i32;
+//!     fn id(&self) -> Self::ID {
+//!         self.x
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Curve {
+//!     #[serde(with = "identified_vec::serde::ordered_map_display")]
+//!     points: IdentifiedVecOf<Point>,
+//! }
+//!
+//! let json = r#"{"points":{"-1":{"x":-1,"y":1},"0":{"x":0,"y":0}}}"#;
+//! let curve = serde_json::from_str::<Curve>(json).unwrap();
+//! assert_eq!(curve.points.ids(), [-1, 0]);
+//! assert_eq!(serde_json::to_string(&curve).unwrap(), json);
+//! ```
+use core::fmt::{Debug, Display};
+use core::hash::{BuildHasher, Hash};
+use core::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::keys::DisplayKeys;
+use super::ordered_map::{deserialize_with_keys, serialize_with_keys};
+use crate::{Identifiable, IdentifiedVec, IdentifiedVecOf};
+
+/// Serializes `identified_vec` as a map from ids, as strings, to elements.
+pub fn serialize<I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug + Display,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    serialize_with_keys::<DisplayKeys, _, _, _, _, _>(identified_vec, serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from a map from ids, as strings, to
+/// elements.
+pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    E::ID: FromStr,
+    <E::ID as FromStr>::Err: Display,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+{
+    deserialize_with_keys::<DisplayKeys, _, _, _, _>(deserializer, |_, _| {})
+}
+
+pub mod injecting_id {
+    //! Like `ordered_map_display`, but injects the key of every element into
+    //! it using `InjectId` when deserializing.
+    use core::fmt::Display;
+    use core::hash::BuildHasher;
+    use core::str::FromStr;
+
+    use serde::{Deserialize, Deserializer};
+
+    use super::super::keys::DisplayKeys;
+    use super::super::ordered_map::deserialize_with_keys;
+    use crate::serde::InjectId;
+    use crate::IdentifiedVecOf;
+
+    pub use super::serialize;
+
+    /// Deserializes an `IdentifiedVecOf` from a map from ids, as strings, to
+    /// elements, injecting the keys into the elements.
+    pub fn deserialize<'de, E, H, D>(deserializer: D) -> Result<IdentifiedVecOf<E, H>, D::Error>
+    where
+        E: InjectId + Deserialize<'de>,
+        E::ID: FromStr,
+        <E::ID as FromStr>::Err: Display,
+        H: BuildHasher + Default,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_keys::<DisplayKeys, _, _, _, _>(deserializer, |element: &mut E, id| {
+            element.inject_id(id.clone())
+        })
+    }
+}
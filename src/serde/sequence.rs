@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    Conflict, ConflictResolutionChoice, Identifiable, IdentifiedVec, IdentifiedVecOf,
+    IsIdentifiedVec,
+};
+
+/// Serializes `identified_vec` as a sequence of elements, like its
+/// `Serialize` impl.
+pub fn serialize<I, E, F, H, S>(
+    identified_vec: &IdentifiedVec<I, E, F, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: Eq + Hash + Clone + Debug,
+    E: Serialize,
+    F: Fn(&E) -> I,
+    H: BuildHasher,
+    S: Serializer,
+{
+    identified_vec.serialize(serializer)
+}
+
+/// Deserializes an `IdentifiedVecOf` from a sequence of elements, using
+/// `choose` to select between elements with the same id.
+pub(crate) fn deserialize_selecting<'de, E, H, D, C>(
+    deserializer: D,
+    choose: C,
+) -> Result<IdentifiedVecOf<E, H>, D::Error>
+where
+    E: Identifiable + Deserialize<'de>,
+    H: BuildHasher + Default,
+    D: Deserializer<'de>,
+    C: FnMut(Conflict<&E::ID, &E>) -> ConflictResolutionChoice,
+{
+    let elements = Vec::<E>::deserialize(deserializer)?;
+    Ok(IdentifiedVecOf::from_iter_select_unique_ids_with(
+        elements,
+        E::id as fn(&E) -> E::ID,
+        choose,
+    ))
+}
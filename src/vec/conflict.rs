@@ -0,0 +1,22 @@
+/// A clash between an element already in an `identified_vec` and a new
+/// element with the same id, passed to the closures resolving duplicates.
+///
+/// Closures choosing between the elements get `Conflict<&ID, &Element>`,
+/// closures merging them get the elements by value, `Conflict<&ID, Element>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Conflict<ID, E> {
+    /// The index of the new, duplicate, element in the input sequence.
+    pub index: usize,
+
+    /// The position of the existing element in the `identified_vec`.
+    pub existing_index: usize,
+
+    /// The id shared by both elements.
+    pub id: ID,
+
+    /// The element already in the `identified_vec`.
+    pub existing: E,
+
+    /// The new element with the same id.
+    pub new: E,
+}
@@ -0,0 +1,128 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{Deref, DerefMut};
+
+/// A mutable reference to an element of an `IdentifiedVec`, which
+/// dereferences to the element.
+///
+/// Since the `IdentifiedVec` uses the id of the element as key in its
+/// internal storage, the id of the element MUST NOT be changed, this guard
+/// verifies that the id of the element is unchanged when it is dropped,
+/// and panics if it is not, unless the thread is already panicking, since
+/// panicking again would abort.
+///
+/// Without the `std` feature it cannot tell if the thread is panicking, so it
+/// always verifies the id, a changed id found while unwinding from another
+/// panic then aborts, rather than silently corrupting the `IdentifiedVec`.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec, IsIdentifiedVecOf};
+///
+/// #[derive(Eq, PartialEq, Clone, Debug)]
+/// struct User {
+///     id: u8,
+///     name: &'static str,
+/// }
+///
+/// impl Identifiable for User {
+///     type ID = u8;
+///     fn id(&self) -> Self::ID {
+///         self.id
+///     }
+/// }
+///
+/// let mut users = IdentifiedVecOf::<User>::from_iter([User { id: 1, name: "Blob" }]);
+/// if let Some(mut user) = users.get_mut(&1) {
+///     user.name = "Blob, Jr.";
+///     // `user.id = 2;` would panic when `user` is dropped.
+/// }
+/// assert_eq!(users.get(&1).map(|u| u.name), Some("Blob, Jr."));
+/// ```
+pub struct ElementMut<'a, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    element: &'a mut E,
+    id: &'a I,
+    id_of_element: &'a dyn Fn(&E) -> I,
+}
+
+impl<'a, I, E> ElementMut<'a, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    pub(crate) fn new(element: &'a mut E, id: &'a I, id_of_element: &'a dyn Fn(&E) -> I) -> Self {
+        Self {
+            element,
+            id,
+            id_of_element,
+        }
+    }
+
+    /// The id of the element, which must not be changed.
+    pub fn id(&self) -> &I {
+        self.id
+    }
+}
+
+impl<I, E> Deref for ElementMut<'_, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        self.element
+    }
+}
+
+impl<I, E> DerefMut for ElementMut<'_, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.element
+    }
+}
+
+impl<I, E> Debug for ElementMut<'_, I, E>
+where
+    E: Debug,
+    I: Eq + Hash + Clone + Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.element.fmt(f)
+    }
+}
+
+impl<I, E> Drop for ElementMut<'_, I, E>
+where
+    I: Eq + Hash + Clone + Debug,
+{
+    fn drop(&mut self) {
+        if might_be_unwinding() {
+            return;
+        }
+        assert_eq!(
+            &(self.id_of_element)(self.element),
+            self.id,
+            "The id of an element must not be mutated"
+        );
+    }
+}
+
+/// Whether the thread is unwinding from a panic, in which case the id of the
+/// element is not verified, as panicking again would abort. Always `false`
+/// without the `std` feature, since it cannot be known.
+#[inline]
+fn might_be_unwinding() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::thread::panicking()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        false
+    }
+}
@@ -0,0 +1,250 @@
+use crate::collections::RandomState;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+
+use crate::{ElementMut, Error, IdentifiedVec, IsIdentifiedVec};
+
+/// A view into a single position of an `identified_vec`, identified by an
+/// id, which is either occupied or vacant, created by `entry`.
+///
+/// Allows upserting without cloning the element. The id is looked up once to
+/// create the entry, and again by the accesses and the insertion through it,
+/// as the ids and the elements are stored in separate hash maps.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{Identifiable, IdentifiedVecOf, IsIdentifiedVec, IsIdentifiedVecOf};
+///
+/// #[derive(Eq, PartialEq, Clone, Debug)]
+/// struct Counter {
+///     id: &'static str,
+///     count: u32,
+/// }
+///
+/// impl Identifiable for Counter {
+///     type ID = &'static str;
+///     fn id(&self) -> Self::ID {
+///         self.id
+///     }
+/// }
+///
+/// let mut counters = IdentifiedVecOf::<Counter>::new();
+/// for id in ["a", "b", "a"] {
+///     counters
+///         .entry(id)
+///         .and_modify(|counter| counter.count += 1)
+///         .or_insert_with(|| Counter { id, count: 1 });
+/// }
+/// assert_eq!(counters.get(&"a").map(|c| c.count), Some(2));
+/// assert_eq!(counters.entry("b").index(), Some(1));
+/// ```
+pub enum Entry<'a, I, E, F = fn(&E) -> I, S = RandomState>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// An element with the id is present.
+    Occupied(OccupiedEntry<'a, I, E, F, S>),
+
+    /// No element with the id is present.
+    Vacant(VacantEntry<'a, I, E, F, S>),
+}
+
+/// A view into the position of an element present in an `identified_vec`.
+pub struct OccupiedEntry<'a, I, E, F = fn(&E) -> I, S = RandomState>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    identified_vec: &'a mut IdentifiedVec<I, E, F, S>,
+    index: usize,
+}
+
+/// A view into an `identified_vec` lacking an element with the id.
+pub struct VacantEntry<'a, I, E, F = fn(&E) -> I, S = RandomState>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    identified_vec: &'a mut IdentifiedVec<I, E, F, S>,
+    id: I,
+}
+
+impl<'a, I, E, F, S> Entry<'a, I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    pub(crate) fn new(identified_vec: &'a mut IdentifiedVec<I, E, F, S>, id: I) -> Self {
+        match identified_vec.index_of_id(&id) {
+            Some(index) => Self::Occupied(OccupiedEntry {
+                identified_vec,
+                index,
+            }),
+            None => Self::Vacant(VacantEntry { identified_vec, id }),
+        }
+    }
+
+    /// The id of this entry.
+    pub fn id(&self) -> &I {
+        match self {
+            Self::Occupied(entry) => entry.id(),
+            Self::Vacant(entry) => entry.id(),
+        }
+    }
+
+    /// The index of the element if present, else `None`.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Self::Occupied(entry) => Some(entry.index()),
+            Self::Vacant(_) => None,
+        }
+    }
+
+    /// Returns the element if present, else appends the element made by
+    /// `make` and returns it.
+    ///
+    /// - Precondition: The made element must have the id of the entry.
+    pub fn or_insert_with<M>(self, make: M) -> ElementMut<'a, I, E>
+    where
+        M: FnOnce() -> E,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(make()),
+        }
+    }
+
+    /// Returns the element if present, else inserts the element made by
+    /// `make` at `index` and returns it.
+    ///
+    /// - Precondition: The made element must have the id of the entry, and
+    ///   `index` must not be greater than the length.
+    pub fn or_insert_at<M>(self, index: usize, make: M) -> ElementMut<'a, I, E>
+    where
+        M: FnOnce() -> E,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert_at(index, make()),
+        }
+    }
+
+    /// Mutates the element using `modify` if present, before any insertion.
+    ///
+    /// - Precondition: `modify` must not change the id of the element.
+    pub fn and_modify<M>(self, modify: M) -> Self
+    where
+        M: FnOnce(&mut E),
+    {
+        match self {
+            Self::Occupied(mut entry) => {
+                modify(&mut entry.get_mut());
+                Self::Occupied(entry)
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, I, E, F, S> OccupiedEntry<'a, I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// The id of the element.
+    pub fn id(&self) -> &I {
+        &self.identified_vec.order[self.index]
+    }
+
+    /// The index of the element.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the element.
+    pub fn get(&self) -> &E {
+        &self.identified_vec[self.index]
+    }
+
+    /// Returns a mutable reference to the element, which panics on drop if
+    /// the id of the element has been changed.
+    pub fn get_mut(&mut self) -> ElementMut<'_, I, E> {
+        self.identified_vec
+            .get_at_index_mut(self.index)
+            .expect("Element at index of entry")
+    }
+
+    /// Converts the entry into a mutable reference to the element, which
+    /// panics on drop if the id of the element has been changed.
+    pub fn into_mut(self) -> ElementMut<'a, I, E> {
+        self.identified_vec
+            .get_at_index_mut(self.index)
+            .expect("Element at index of entry")
+    }
+
+    /// Removes the element from the `identified_vec` and returns it.
+    ///
+    /// - Complexity: O(`count`)
+    pub fn remove(self) -> E {
+        self.identified_vec.remove_at(self.index)
+    }
+}
+
+impl<'a, I, E, F, S> VacantEntry<'a, I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// The id of the entry.
+    pub fn id(&self) -> &I {
+        &self.id
+    }
+
+    /// Takes ownership of the id of the entry.
+    pub fn into_id(self) -> I {
+        self.id
+    }
+
+    /// Appends `element` and returns it.
+    ///
+    /// - Precondition: `element` must have the id of the entry.
+    pub fn insert(self, element: E) -> ElementMut<'a, I, E> {
+        let index = self.identified_vec.len();
+        self.insert_at(index, element)
+    }
+
+    /// Inserts `element` at `index` and returns it.
+    ///
+    /// - Precondition: `element` must have the id of the entry, and `index`
+    ///   must not be greater than the length.
+    pub fn insert_at(self, index: usize, element: E) -> ElementMut<'a, I, E> {
+        self.try_insert_at(index, element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Inserts `element` at `index` and returns it, or returns
+    /// `Error::IdMismatch` if `element` does not have the id of the entry or
+    /// `Error::IndexOutOfBounds` if `index` is greater than the length.
+    pub fn try_insert_at(self, index: usize, element: E) -> Result<ElementMut<'a, I, E>, Error<I>> {
+        let id = (self.identified_vec._id_of_element)(&element);
+        if id != self.id {
+            return Err(Error::IdMismatch {
+                expected: self.id,
+                found: id,
+                index,
+            });
+        }
+        self.identified_vec.try_insert(element, index)?;
+        Ok(self
+            .identified_vec
+            .get_at_index_mut(index)
+            .expect("Inserted element"))
+    }
+}
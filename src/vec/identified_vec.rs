@@ -1,13 +1,25 @@
+use super::identified_vec_diff::longest_increasing_subsequence;
 use super::ConflictResolutionChoice;
+use crate::collections::{HashMap, RandomState};
+use crate::identified_vec_extract_if::IdentifiedVecExtractIf;
 use crate::identified_vec_into_iterator::IdentifiedVecIntoIterator;
+use crate::iterators::identified_vec_iter_mut::IdentifiedVecIterMut;
 use crate::iterators::identified_vec_iterator::IdentifiedVecIterator;
-use crate::Error;
-use std::collections::HashMap;
-use std::fmt::{Debug, Display};
-use std::hash::{Hash, Hasher};
+use crate::{
+    Conflict, ElementMut, Entry, Error, IdentifiedVecChange, IdentifiedVecDiff, MergeConflict,
+};
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+use core::convert::Infallible;
+use core::fmt::{Debug, Display};
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::{Index, RangeBounds};
 
 use super::is_identified_vec::IsIdentifiedVec;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
 /// An ordered collection of identifiable elements.
 ///
 /// Similar to the standard `Vec`, identified vecs maintain their elements in a particular
@@ -129,6 +141,21 @@ use super::is_identified_vec::IsIdentifiedVec;
 /// let numbers = IdentifiedVec::<u32, u32>::new_identifying_element(|e| *e);
 /// ```
 ///
+/// The closure may capture its environment, in which case the type of the
+/// `IdentifiedVec` is inferred from it:
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{IdentifiedVec, IsIdentifiedVec};
+///
+/// let prefix = "tenant_a";
+/// let mut names = IdentifiedVec::<String, &str, _>::new_identifying_element(
+///     move |e: &&str| format!("{prefix}/{e}")
+/// );
+/// names.append("Alice");
+/// assert_eq!(names.ids(), ["tenant_a/Alice"]);
+/// ```
+///
 /// # Motivation
 /// None of the std collections `BTreeSet` and `HashSet` retain insertion order, `Vec` retains
 /// insertion order, however, it allows for duplicates. So if you want a collection of unique
@@ -154,28 +181,42 @@ use super::is_identified_vec::IsIdentifiedVec;
 ///
 /// ## Implementation Details
 ///
-/// An identified vec consists of a Vec and a HashMap of id-element pairs. An element's id
-/// should not be mutated in place, as it will drift from its associated dictionary key. Identified
-/// bec is designed to avoid this invariant. Mutating an element's id will result in a runtime error.
-#[derive(Debug, Clone)]
-pub struct IdentifiedVec<I, E>
+/// An identified vec consists of a Vec of ids, a HashMap of id-element pairs and a HashMap of
+/// id-index pairs, the latter making lookup of the index of an element by its id a constant time
+/// operation, much like an index map. An element's id should not be mutated in place, as it will
+/// drift from its associated dictionary key. Identified bec is designed to avoid this invariant.
+/// Mutating an element's id will result in a runtime error.
+#[derive(Clone)]
+pub struct IdentifiedVec<I, E, F = fn(&E) -> I, S = RandomState>
 where
     I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
 {
     /// The holder of the insertion order
     pub(crate) order: Vec<I>,
 
+    /// The position in `order` of every id, allowing constant time lookup
+    /// of the index of an element.
+    pub(crate) indices: HashMap<I, usize, S>,
+
     /// The storage of elements.
-    pub(crate) elements: HashMap<I, E>,
+    pub(crate) elements: HashMap<I, E, S>,
 
-    /// Function which extracts the I of an E.
-    pub(crate) _id_of_element: fn(&E) -> I,
+    /// Function which extracts the I of an E, either a function pointer or
+    /// a closure, possibly capturing its environment.
+    pub(crate) _id_of_element: F,
 }
 
-impl<I, E> IsIdentifiedVec<E, I> for IdentifiedVec<I, E>
+impl<I, E, F, S> IsIdentifiedVec<E, I> for IdentifiedVec<I, E, F, S>
 where
     I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
 {
+    type IdOfElement = F;
+    type Hasher = S;
+
     ////////////////////
     //  Constructors  //
     ////////////////////
@@ -183,10 +224,14 @@ where
     /// Constructs a new, empty `IdentifiedVec<I, E>` with the specified
     /// `id_of_element` closure
     #[inline]
-    fn new_identifying_element(id_of_element: fn(&E) -> I) -> Self {
+    fn new_identifying_element(id_of_element: F) -> Self
+    where
+        S: Default,
+    {
         Self {
             order: Vec::new(),
-            elements: HashMap::new(),
+            indices: HashMap::default(),
+            elements: HashMap::default(),
             _id_of_element: id_of_element,
         }
     }
@@ -203,27 +248,36 @@ where
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
     ///   - id_of_element: The function which extracts the identifier for an element,
-    ///   - combine: Closure trying to combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
+    ///   - combine: Closure trying to combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `I`
     ///   implements high-quality hashing.
     #[cfg(not(tarpaulin_include))] // false negative
     #[inline]
-    fn try_from_iter_select_unique_ids_with<Er, It>(
+    fn try_from_iter_select_unique_ids_with<Er, It, C>(
         elements: It,
-        id_of_element: fn(&E) -> I,
-        combine: fn((usize, &E, &E)) -> Result<ConflictResolutionChoice, Er>,
+        id_of_element: F,
+        mut combine: C,
     ) -> Result<Self, Er>
     where
+        C: FnMut(Conflict<&I, &E>) -> Result<ConflictResolutionChoice, Er>,
         It: IntoIterator<Item = E>,
+        S: Default,
     {
         let mut _order = Vec::<I>::new();
-        let mut _elements = HashMap::<I, E>::new();
+        let mut _indices = HashMap::<I, usize, S>::default();
+        let mut _elements = HashMap::<I, E, S>::default();
 
-        for element in elements.into_iter() {
+        for (index, element) in elements.into_iter().enumerate() {
             let id = id_of_element(&element);
             match _elements.remove(&id) {
-                Some(existing) => match combine((_order.len(), &existing, &element)) {
+                Some(existing) => match combine(Conflict {
+                    index,
+                    existing_index: _indices[&id],
+                    id: &id,
+                    existing: &existing,
+                    new: &element,
+                }) {
                     Err(e) => return Err(e),
                     Ok(choice) => match choice {
                         ConflictResolutionChoice::ChooseFirst => {
@@ -236,6 +290,7 @@ where
                 },
                 None => {
                     _elements.insert(id.clone(), element);
+                    _indices.insert(id.clone(), _order.len());
                     _order.push(id);
                     None
                 }
@@ -244,6 +299,7 @@ where
 
         Ok(Self {
             order: _order,
+            indices: _indices,
             _id_of_element: id_of_element,
             elements: _elements,
         })
@@ -261,32 +317,42 @@ where
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
     ///   - id_of_element: The function which extracts the identifier for an element,
-    ///   - combine: Closure used combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
+    ///   - combine: Closure used combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `I`
     ///   implements high-quality hashing.
     #[cfg(not(tarpaulin_include))] // false negative
     #[inline]
-    fn from_iter_select_unique_ids_with<It>(
+    fn from_iter_select_unique_ids_with<It, C>(
         elements: It,
-        id_of_element: fn(&E) -> I,
-        combine: fn((usize, &E, &E)) -> ConflictResolutionChoice,
+        id_of_element: F,
+        mut combine: C,
     ) -> Self
     where
+        C: FnMut(Conflict<&I, &E>) -> ConflictResolutionChoice,
         It: IntoIterator<Item = E>,
+        S: Default,
     {
         let mut _order = Vec::<I>::new();
-        let mut _elements = HashMap::<I, E>::new();
+        let mut _indices = HashMap::<I, usize, S>::default();
+        let mut _elements = HashMap::<I, E, S>::default();
 
-        for element in elements.into_iter() {
+        for (index, element) in elements.into_iter().enumerate() {
             let id = id_of_element(&element);
             match _elements.remove(&id) {
-                Some(existing) => match combine((_order.len(), &existing, &element)) {
+                Some(existing) => match combine(Conflict {
+                    index,
+                    existing_index: _indices[&id],
+                    id: &id,
+                    existing: &existing,
+                    new: &element,
+                }) {
                     ConflictResolutionChoice::ChooseFirst => _elements.insert(id.clone(), existing),
                     ConflictResolutionChoice::ChooseLast => _elements.insert(id.clone(), element),
                 },
                 None => {
                     _elements.insert(id.clone(), element);
+                    _indices.insert(id.clone(), _order.len());
                     _order.push(id);
                     None
                 }
@@ -295,11 +361,79 @@ where
 
         Self {
             order: _order,
+            indices: _indices,
             _id_of_element: id_of_element,
             elements: _elements,
         }
     }
 
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate identity into a new element.
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - id_of_element: The function which extracts the identifier for an element,
+    ///   - merge: Closure trying to merge elements with duplicate ids, given the `Conflict` into a new element, which must have the same id, or `Err` if you prefer.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`,
+    ///   or the first `Err` of `merge`.
+    /// - Warning: `merge` takes ownership of both elements of the `Conflict`, so if it returns `Err`, or
+    ///   panics, they are dropped, together with every element consumed from `elements` so far.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `I`
+    ///   implements high-quality hashing.
+    #[inline]
+    fn try_from_iter_merge_unique_ids_with<Er, It, M>(
+        elements: It,
+        id_of_element: F,
+        merge: M,
+    ) -> Result<Self, Er>
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(Conflict<&I, E>) -> Result<E, Er>,
+        S: Default,
+    {
+        let mut identified_vec = Self::new_identifying_element(id_of_element);
+        identified_vec._try_append_other_merging(elements, merge)?;
+        Ok(identified_vec)
+    }
+
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate identity into a new element.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// // (account, amount)
+    /// let transactions = [("a", 1), ("b", 2), ("a", 3)];
+    /// let balances = IdentifiedVec::<&str, (&str, i32), _>::from_iter_merge_unique_ids_with(
+    ///     transactions,
+    ///     |t| t.0,
+    ///     |conflict| (*conflict.id, conflict.existing.1 + conflict.new.1),
+    /// );
+    /// assert_eq!(balances.elements(), [&("a", 4), &("b", 2)]);
+    /// ```
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - id_of_element: The function which extracts the identifier for an element,
+    ///   - merge: Closure merging elements with duplicate ids, given the `Conflict` into a new element, which must have the same id.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `I`
+    ///   implements high-quality hashing.
+    #[inline]
+    fn from_iter_merge_unique_ids_with<It, M>(elements: It, id_of_element: F, mut merge: M) -> Self
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(Conflict<&I, E>) -> E,
+        S: Default,
+    {
+        let mut identified_vec = Self::new_identifying_element(id_of_element);
+        identified_vec
+            ._try_append_other_merging(elements, |conflict| Ok::<E, Infallible>(merge(conflict)))
+            .unwrap_or_else(|never| match never {});
+        identified_vec
+    }
+
     ////////////////////
     //  Public Get    //
     ////////////////////
@@ -317,6 +451,7 @@ where
     fn len(&self) -> usize {
         if cfg!(debug_assertions) {
             assert_eq!(self.order.len(), self.elements.len());
+            assert_eq!(self.order.len(), self.indices.len());
         }
         self.order.len()
     }
@@ -361,7 +496,7 @@ where
     /// - Complexity: Expected to be O(1) on average, if `I` implements high-quality hashing.
     #[inline]
     fn index_of_id(&self, id: &I) -> Option<usize> {
-        self.order.iter().position(|i| i == id)
+        self.indices.get(id).copied()
     }
 
     ////////////////////
@@ -383,7 +518,7 @@ where
     /// Returns `true` if the `identified_vec` contains the `element.`
     #[inline]
     fn contains(&self, element: &E) -> bool {
-        self.elements.contains_key(&self.id(&element))
+        self.elements.contains_key(&self.id(element))
     }
 
     /// Returns `true if the `identified_vec` contains an element for the specified `id`
@@ -404,6 +539,41 @@ where
         self.order.get(index).and_then(|id| self.get(id))
     }
 
+    /// Returns a mutable reference to the element corresponding to the `id`,
+    /// if found, else `None`.
+    ///
+    /// The returned `ElementMut` panics on drop if the id of the element
+    /// has been changed.
+    ///
+    /// - Complexity: Expected to be O(1) on average, if `I` implements high-quality hashing.
+    #[inline]
+    fn get_mut(&mut self, id: &I) -> Option<ElementMut<'_, I, E>> {
+        self.index_of_id(id)
+            .and_then(|index| self.get_at_index_mut(index))
+    }
+
+    /// Returns a mutable reference to the element at index if found, else `None`.
+    ///
+    /// The returned `ElementMut` panics on drop if the id of the element
+    /// has been changed.
+    ///
+    /// - Complexity: Expected to be O(1) on average, if `I` implements high-quality hashing.
+    #[inline]
+    fn get_at_index_mut(&mut self, index: usize) -> Option<ElementMut<'_, I, E>> {
+        let id = self.order.get(index)?;
+        let element = self.elements.get_mut(id).expect("Element for existing id");
+        Some(ElementMut::new(element, id, &self._id_of_element))
+    }
+
+    /// Returns the `Entry` of `id`, occupied if an element with `id` is present,
+    /// else vacant, for in place manipulation.
+    ///
+    /// - Complexity: Expected to be O(1) on average, if `I` implements high-quality hashing.
+    #[inline]
+    fn entry(&mut self, id: I) -> Entry<'_, I, E, F, S> {
+        Entry::new(self, id)
+    }
+
     /// Append a new member to the end of the `identified_vec`, if the `identified_vec` doesn't already contain it.
     ///
     /// - Parameter item: The element to add to the `identified_vec`.
@@ -431,6 +601,48 @@ where
         other.into_iter().for_each(|i| _ = self.append(i))
     }
 
+    /// Append the contents of an iterator to the end of the set, merging elements which are already
+    /// members into the existing ones, in place, keeping their position.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// // (account, amount)
+    /// let mut balances = IdentifiedVec::<&str, (&str, i32), _>::new_identifying_element(|t| t.0);
+    /// balances.append_other_merging([("a", 1), ("b", 2), ("a", 3)], |existing, new| {
+    ///     existing.1 += new.1
+    /// });
+    /// assert_eq!(balances.elements(), [&("a", 4), &("b", 2)]);
+    /// ```
+    ///
+    /// - Parameter elements: A finite sequence of elements to append.
+    /// - Parameter merge: Closure merging the new element into the existing element, which
+    ///   MUST NOT change its id. If `merge` panics the existing element stays in the
+    ///   `identified_vec`, with whatever changes `merge` made before panicking.
+    /// - Panics: If `merge` changes the id of the existing element.
+    /// - Complexity: The operation is expected to perform amortized O(1) copy, hash, and compare
+    ///   operations on the `Element` type, if it implements high-quality hashing.
+    #[inline]
+    fn append_other_merging<It, M>(&mut self, other: It, mut merge: M)
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(&mut E, E),
+    {
+        for element in other {
+            let id = self.id(&element);
+            match self.elements.get_mut(&id) {
+                Some(existing) => merge(
+                    &mut ElementMut::new(existing, &id, &self._id_of_element),
+                    element,
+                ),
+                None => {
+                    self._update_value(element, id);
+                }
+            }
+        }
+    }
+
     /// Adds the given element to the `identified_vec` unconditionally, either appending it to the `identified_vec``, or
     /// replacing an existing value if it's already present.
     ///
@@ -463,49 +675,83 @@ where
             &id, old_id,
             "The replacement item must match the identity of the original"
         );
-        return self
-            ._update_value_inserting_at(element, id, index)
+        self._update_value_inserting_at(element, id, index)
             .0
-            .expect("Replaced old value");
+            .expect("Replaced old value")
+    }
+
+    /// Replace the member at the given index with a new value of the same identity,
+    /// without panicking.
+    ///
+    /// - Parameter item: The new value that should replace the original element.
+    /// - Parameter index: The index of the element to be replaced.
+    /// - Returns: The original element that was replaced, or `Error::IndexOutOfBounds` if
+    ///   `index` is not a valid index, or `Error::IdMismatch` if `item` does not match the
+    ///   identity of the original value.
+    /// - Complexity: Amortized O(1).
+    #[inline]
+    fn try_update_at(&mut self, element: E, index: usize) -> Result<E, Error<I>> {
+        let expected = match self.order.get(index) {
+            Some(expected) => expected,
+            None => {
+                return Err(Error::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                })
+            }
+        };
+        let id = self.id(&element);
+        if &id != expected {
+            return Err(Error::IdMismatch {
+                expected: expected.clone(),
+                found: id,
+                index,
+            });
+        }
+        Ok(self
+            .elements
+            .insert(id, element)
+            .expect("Replaced old value"))
     }
 
     /// Returns `false` if no element of `id` was found, otherwise if found, this
     /// existing element gets updated by `mutate` closure and this function returns
     /// `true`.
     #[inline]
-    fn update_with<F>(&mut self, id: &I, mut mutate: F) -> bool
+    fn update_with<M>(&mut self, id: &I, mut mutate: M) -> bool
     where
-        F: FnMut(&mut E),
+        M: FnMut(&mut E),
     {
-        if !self.contains_id(id) {
-            return false;
+        match self.get_mut(id) {
+            Some(mut existing) => {
+                mutate(&mut existing);
+                true
+            }
+            None => false,
         }
-        let mut existing = self.elements.remove(id).expect("Element for existing id");
-        mutate(&mut existing);
-        self.elements.insert(id.clone(), existing);
-        true
     }
 
     #[allow(unused_mut)]
     #[inline]
-    fn try_update_with<F, Er>(&mut self, id: &I, mut mutate: F) -> Result<bool, Er>
+    fn try_update_with<M, Er>(&mut self, id: &I, mut mutate: M) -> Result<bool, Er>
     where
-        F: FnMut(&mut E) -> Result<E, Er>,
+        M: FnMut(&mut E) -> Result<E, Er>,
     {
         if !self.contains_id(id) {
             return Ok(false);
         }
         let mut existing = self.elements.remove(id).expect("Element for existing id");
 
-        mutate(&mut existing)
-            .map(|updated| {
+        match mutate(&mut existing) {
+            Ok(updated) => {
                 self.elements.insert(id.clone(), updated);
-                true
-            })
-            .map_err(|e| {
+                Ok(true)
+            }
+            Err(e) => {
                 self.elements.insert(id.clone(), existing);
-                e
-            })
+                Err(e)
+            }
+        }
     }
 
     /// Try to update the given element to the `identified_vec` if a element with the same ID is already present.
@@ -515,10 +761,10 @@ where
     /// - Complexity: The operation is expected to perform amortized O(1) copy, hash, and compare
     ///   operations on the `ID` type, if it implements high-quality hashing.
     #[inline]
-    fn try_update(&mut self, element: E) -> Result<E, Error> {
+    fn try_update(&mut self, element: E) -> Result<E, Error<I>> {
         let id = self.id(&element);
         if self.get(&id).is_none() {
-            return Err(Error::ExpectedElementNotPresent(format!("{:#?}", id)));
+            return Err(Error::ExpectedElementNotPresent { id });
         }
 
         Ok(self
@@ -543,12 +789,36 @@ where
     fn insert(&mut self, element: E, at: usize) -> (bool, usize) {
         let id = self.id(&element);
         if let Some(existing) = self.index_of_id(&id) {
-            return (false, existing.clone());
+            return (false, existing);
         }
         self._update_value_inserting_at(element, id, at);
         (true, at)
     }
 
+    /// Insert a new member to this identified_vec at the specified index, if the identified_vec doesn't already contain
+    /// it, without panicking.
+    ///
+    /// - Parameter element: The element to insert.
+    /// - Returns: Like `insert`, a pair `(inserted, index)`, or `Error::IndexOutOfBounds` if
+    ///   the element is not already present and `at` is greater than the length.
+    /// - Complexity: The operation is expected to perform amortized O(`self.count`) copy, hash, and
+    ///   compare operations on the `I` type, if it implements high-quality hashing.
+    #[inline]
+    fn try_insert(&mut self, element: E, at: usize) -> Result<(bool, usize), Error<I>> {
+        let id = self.id(&element);
+        if let Some(existing) = self.index_of_id(&id) {
+            return Ok((false, existing));
+        }
+        if at > self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: at,
+                len: self.len(),
+            });
+        }
+        self._update_value_inserting_at(element, id, at);
+        Ok((true, at))
+    }
+
     /// Adds the given element into the set unconditionally, either inserting it at the specified
     /// index, or replacing an existing value if it's already present.
     ///
@@ -603,18 +873,19 @@ where
     ///
     /// - Parameter id: The id of the element to be removed from the `identified_vec`.
     /// - Returns: The element that was removed, or `None` if the element was not present in the array.
-    /// - Complexity: O(`count`)
+    /// - Complexity: O(`count`) in general, O(1) when removing the last element.
     #[cfg(not(tarpaulin_include))] // false negative
     #[inline]
     fn remove_by_id(&mut self, id: &I) -> Option<E> {
-        match self.index_of_id(id) {
+        match self.indices.remove(id) {
             Some(index) => {
                 self.order.remove(index);
-                return self.elements.remove(id);
+                self._reindex_from(index);
+                self.elements.remove(id)
             }
             None => {
                 assert!(!self.elements.contains_key(id));
-                return None;
+                None
             }
         }
     }
@@ -647,26 +918,75 @@ where
         let id = self
             .order
             .get(index)
-            .expect("Precondition failure, index out of bounds");
-        let removed = self.elements.remove(id).expect("E of existing id");
-        self.order.remove(index);
-        return removed;
+            .expect("Precondition failure, index out of bounds")
+            .clone();
+        self.remove_by_id(&id).expect("E of existing id")
     }
 
-    /// Removes all the elements at the specified `offsets` from the `identified_vec`.
+    /// Removes all the elements at the specified `offsets` from the `identified_vec`,
+    /// the `offsets` need not be sorted nor unique.
     ///
     /// - Parameter offsets: The offsets of all elements to be removed.
-    /// - Complexity: O(*n*) where *n* is the length of the `identified_vec`.
+    /// - Precondition: Every offset must be a valid index, else no element is removed.
+    /// - Complexity: O(*n* + *k*) where *n* is the length of the `identified_vec` and *k*
+    ///   the number of `offsets`, in a single pass over the `identified_vec`.
     #[inline]
     fn remove_at_offsets<It>(&mut self, offsets: It)
     where
         It: IntoIterator<Item = usize>,
     {
-        let mut internal_offset = 0;
-        offsets.into_iter().for_each(|i| {
-            _ = self.remove_at(i - internal_offset);
-            internal_offset += 1;
-        })
+        self.try_remove_at_offsets(offsets)
+            .expect("Precondition failure, index out of bounds")
+    }
+
+    /// Removes and returns the element at the specified position, without panicking.
+    ///
+    /// - Parameter index: The position of the element to remove.
+    /// - Returns: The removed element, or `Error::IndexOutOfBounds` if `index` is not a
+    ///   valid index.
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn try_remove_at(&mut self, index: usize) -> Result<E, Error<I>> {
+        match self.order.get(index) {
+            Some(id) => {
+                let id = id.clone();
+                Ok(self.remove_by_id(&id).expect("E of existing id"))
+            }
+            None => Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Removes all the elements at the specified `offsets` from the `identified_vec`,
+    /// without panicking, the `offsets` need not be sorted nor unique.
+    ///
+    /// - Parameter offsets: The offsets of all elements to be removed.
+    /// - Returns: `Error::IndexOutOfBounds` for the first offset which is not a valid index,
+    ///   in which case no element is removed.
+    /// - Complexity: O(*n* + *k*) where *n* is the length of the `identified_vec` and *k*
+    ///   the number of `offsets`, in a single pass over the `identified_vec`.
+    #[inline]
+    fn try_remove_at_offsets<It>(&mut self, offsets: It) -> Result<(), Error<I>>
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        let len = self.len();
+        let offsets = offsets.into_iter().collect::<Vec<usize>>();
+        if let Some(&index) = offsets.iter().find(|&&index| index >= len) {
+            return Err(Error::IndexOutOfBounds { index, len });
+        }
+        let mut is_removed = vec![false; len];
+        for offset in offsets {
+            is_removed[offset] = true;
+        }
+        let mut offset = 0;
+        self._retain_with(|_, _, _| {
+            offset += 1;
+            !is_removed[offset - 1]
+        });
+        Ok(())
     }
 
     /// Try append a new member to the end of the `identified_vec`, if the `identified_vec` already contains the element a Error will be returned.
@@ -678,153 +998,1317 @@ where
     /// - Complexity: The operation is expected to perform O(1) copy, hash, and compare operations on
     ///   the `ID` type, if it implements high-quality hashing.
     #[inline]
-    fn try_append_new(&mut self, element: E) -> Result<(bool, usize), Error> {
+    fn try_append_new(&mut self, element: E) -> Result<(bool, usize), Error<I>> {
         let id = self.id(&element);
 
-        if self.contains_id(&id) {
-            return Err(Error::ElementWithSameIDFound(format!("{:#?}", id)));
+        if let Some(index) = self.index_of_id(&id) {
+            return Err(Error::ElementWithSameIDFound { id, index });
         }
 
         Ok(self.append(element))
     }
 
     #[inline]
-    fn iter(&self) -> IdentifiedVecIterator<I, E> {
+    fn iter(&self) -> IdentifiedVecIterator<'_, I, E, S> {
         IdentifiedVecIterator::new(self)
     }
-}
-
-pub trait ItemsCloned<Element>
-where
-    Element: Clone,
-{
-    fn items(&self) -> Vec<Element>;
-}
 
-impl<I, E> ItemsCloned<E> for IdentifiedVec<I, E>
-where
-    E: Clone,
-    I: Eq + Hash + Clone + Debug,
-{
-    /// A read-only collection of clones of the elements contained in this array, as a `Vec<Elements>`.
-    ///
-    /// N.B. that this method is not constant time.
+    /// Returns an iterator over mutable references to the elements, in order.
     ///
-    /// Use `self.elements()` if you are looking for a collection of references.
+    /// The yielded `ElementMut`s panic on drop if the id of the element
+    /// has been changed.
     ///
-    /// - Complexity: O(n)
+    /// - Complexity: O(n) to create the iterator.
     #[inline]
-    fn items(&self) -> Vec<E> {
-        self.iter().map(|e| e.clone()).collect()
-    }
-}
-
-impl<I, E> IntoIterator for IdentifiedVec<I, E>
-where
-    I: Eq + Hash + Clone + Debug,
-{
-    type Item = E;
-    type IntoIter = IdentifiedVecIntoIterator<I, E>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter::new(self)
+    fn iter_mut(&mut self) -> IdentifiedVecIterMut<'_, I, E> {
+        IdentifiedVecIterMut::new(self)
     }
-}
 
-impl<I, E> IdentifiedVec<I, E> where I: Eq + Hash + Clone + Debug {}
-
-///////////////////////
-////      Eq        ///
-///////////////////////
-impl<I, E> PartialEq for IdentifiedVec<I, E>
-where
-    E: PartialEq,
-    I: Eq + Hash + Clone + Debug,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.elements() == other.elements()
+    /// A collection of mutable references to the elements contained in this
+    /// `identified_vec`, in order, the mutable counterpart of `elements()`.
+    ///
+    /// - Complexity: O(n)
+    #[inline]
+    fn values_mut(&mut self) -> Vec<ElementMut<'_, I, E>> {
+        self.iter_mut().collect()
     }
-}
 
-impl<I, E> Eq for IdentifiedVec<I, E>
-where
-    E: Eq,
-    I: Eq + Hash + Clone + Debug,
-{
-}
+    ////////////////////
+    ////  Sort        ///
+    ////////////////////
 
-///////////////////////
-////      Hash      ///
-///////////////////////
-impl<I, E> Hash for IdentifiedVec<I, E>
-where
-    E: Hash,
-    I: Eq + Hash + Clone + Debug,
-{
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.elements().hash(state);
+    /// Sorts the `identified_vec` in place, preserving the relative order of
+    /// equal elements.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([3, 1, 2]);
+    /// numbers.sort();
+    /// assert_eq!(numbers.elements(), [&1, &2, &3]);
+    /// assert_eq!(numbers.index_of_id(&3), Some(2));
+    /// ```
+    ///
+    /// - Complexity: O(*n* log *n*)
+    #[inline]
+    fn sort(&mut self)
+    where
+        E: Ord,
+    {
+        self.sort_by(E::cmp)
     }
-}
 
-///////////////////////
-////      Display   ///
-///////////////////////
-impl<I, E> Display for IdentifiedVec<I, E>
-where
-    E: Debug,
-    I: Eq + Hash + Clone + Debug,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.elements().fmt(f)
+    /// Sorts the `identified_vec` in place using the given `compare` function,
+    /// preserving the relative order of equal elements.
+    ///
+    /// - Parameter compare: A function returning the ordering of two elements.
+    /// - Complexity: O(*n* log *n*)
+    #[inline]
+    fn sort_by<C>(&mut self, mut compare: C)
+    where
+        C: FnMut(&E, &E) -> Ordering,
+    {
+        let elements = &self.elements;
+        self.order
+            .sort_by(|lhs, rhs| compare(&elements[lhs], &elements[rhs]));
+        self._reindex_from(0);
     }
-}
 
-///////////////////////
-////    PRIVATE     ///
-///////////////////////
-impl<I, E> IdentifiedVec<I, E>
-where
-    I: Eq + Hash + Clone + Debug,
-{
-    /// Next index for element appended
+    /// Sorts the `identified_vec` in place using the key extracted by `key`,
+    /// preserving the relative order of elements with equal keys.
+    ///
+    /// - Parameter key: A function returning the key to sort an element by.
+    /// - Complexity: O(*m* *n* log *n*), where the key function is O(*m*).
     #[inline]
-    fn end_index(&self) -> usize {
-        self.len()
+    fn sort_by_key<K, C>(&mut self, mut key: C)
+    where
+        C: FnMut(&E) -> K,
+        K: Ord,
+    {
+        self.sort_by(|lhs, rhs| key(lhs).cmp(&key(rhs)))
     }
 
-    /// Returns the I of an E
+    /// Sorts the `identified_vec` in place using the given `compare` function,
+    /// without preserving the relative order of equal elements.
+    ///
+    /// - Parameter compare: A function returning the ordering of two elements.
+    /// - Complexity: O(*n* log *n*)
     #[inline]
-    fn id(&self, of: &E) -> I {
-        (self._id_of_element)(of)
+    fn sort_unstable_by<C>(&mut self, mut compare: C)
+    where
+        C: FnMut(&E, &E) -> Ordering,
+    {
+        let elements = &self.elements;
+        self.order
+            .sort_unstable_by(|lhs, rhs| compare(&elements[lhs], &elements[rhs]));
+        self._reindex_from(0);
     }
 
-    /// Inserting I at an index, returning if it did, if not, the index of the existing.
-    #[cfg(not(tarpaulin_include))] // false negative
+    /// Sorts the `identified_vec` in place using the key extracted by `key`,
+    /// which is called at most once per element.
+    ///
+    /// - Parameter key: A function returning the key to sort an element by.
+    /// - Complexity: O(*m* *n* + *n* log *n*), where the key function is O(*m*).
     #[inline]
-    fn _insert_id_at(&mut self, id: I, index: usize) -> (bool, usize) {
-        match self.index_of_id(&id) {
-            Some(existing) => (false, existing),
-            None => {
-                self.order.insert(index, id);
-                (true, index)
-            }
-        }
+    fn sort_by_cached_key<K, C>(&mut self, mut key: C)
+    where
+        C: FnMut(&E) -> K,
+        K: Ord,
+    {
+        let elements = &self.elements;
+        self.order.sort_by_cached_key(|id| key(&elements[id]));
+        self._reindex_from(0);
     }
 
+    /// Sorts the `identified_vec` in place by the ids of its elements.
+    ///
+    /// - Complexity: O(*n* log *n*)
     #[inline]
-    fn _update_value(&mut self, element: E, for_key: I) -> Option<E> {
-        let value = element;
-        let key = for_key;
-
-        let maybe_old = self.elements.remove(&key);
-        self.elements.insert(key.clone(), value);
-
-        if maybe_old.is_some() {
-            return maybe_old;
-        } else {
-            self.order.push(key);
-            None
-        }
+    fn sort_by_id(&mut self)
+    where
+        I: Ord,
+    {
+        self.order.sort();
+        self._reindex_from(0);
+    }
+
+    ////////////////////
+    ////  Reorder     ///
+    ////////////////////
+
+    /// Moves the element at `from` so that it ends up at position `to`,
+    /// shifting the elements in between.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4]);
+    /// numbers.move_element(0, 2);
+    /// assert_eq!(numbers.elements(), [&2, &3, &1, &4]);
+    /// ```
+    ///
+    /// - Parameter from: The current position of the element to move.
+    /// - Parameter to: The position the element will have after the move.
+    /// - Precondition: Both `from` and `to` must be valid indices of the collection.
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn move_element(&mut self, from: usize, to: usize) {
+        let len = self.len();
+        assert!(
+            from < len && to < len,
+            "Precondition failure, index out of bounds"
+        );
+        if from < to {
+            self.order[from..=to].rotate_left(1);
+        } else {
+            self.order[to..=from].rotate_right(1);
+        }
+        self._reindex_from(from.min(to));
+    }
+
+    /// Moves all the elements at the specified `offsets` to the specified
+    /// destination offset, preserving their relative order, like Swift's
+    /// `move(fromOffsets:toOffset:)`.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4, 5]);
+    /// numbers.move_from_offsets([0, 2], 4);
+    /// assert_eq!(numbers.elements(), [&2, &4, &1, &3, &5]);
+    /// ```
+    ///
+    /// - Parameter offsets: The offsets of all elements to be moved.
+    /// - Parameter to_offset: The offset, in the collection before the move, to
+    ///   insert the moved elements before, which may be equal to `len()`.
+    /// - Precondition: All offsets must be valid indices and `to_offset` must
+    ///   not be greater than `len()`.
+    /// - Complexity: O(*n*) where *n* is the length of the `identified_vec`.
+    #[inline]
+    fn move_from_offsets<It>(&mut self, offsets: It, to_offset: usize)
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        let len = self.len();
+        assert!(
+            to_offset <= len,
+            "Precondition failure, index out of bounds"
+        );
+        let mut is_moved = vec![false; len];
+        for offset in offsets {
+            assert!(offset < len, "Precondition failure, index out of bounds");
+            is_moved[offset] = true;
+        }
+        let mut before = Vec::with_capacity(len);
+        let mut moved = Vec::new();
+        let mut after = Vec::new();
+        for (index, id) in core::mem::take(&mut self.order).into_iter().enumerate() {
+            if is_moved[index] {
+                moved.push(id);
+            } else if index < to_offset {
+                before.push(id);
+            } else {
+                after.push(id);
+            }
+        }
+        before.append(&mut moved);
+        before.append(&mut after);
+        self.order = before;
+        self._reindex_from(0);
+    }
+
+    /// Swaps the elements at positions `i` and `j`.
+    ///
+    /// - Precondition: Both `i` and `j` must be valid indices of the collection.
+    /// - Complexity: O(1)
+    #[inline]
+    fn swap_at(&mut self, i: usize, j: usize) {
+        self.order.swap(i, j);
+        *self
+            .indices
+            .get_mut(&self.order[i])
+            .expect("Index of existing id") = i;
+        *self
+            .indices
+            .get_mut(&self.order[j])
+            .expect("Index of existing id") = j;
+    }
+
+    /// Swaps the positions of the elements identified by `lhs` and `rhs`.
+    ///
+    /// - Returns: `true` if both elements were present and got swapped, else `false`.
+    /// - Complexity: O(1)
+    #[inline]
+    fn swap_ids(&mut self, lhs: &I, rhs: &I) -> bool {
+        match (self.index_of_id(lhs), self.index_of_id(rhs)) {
+            (Some(i), Some(j)) => {
+                self.swap_at(i, j);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Reverses the order of the elements in place.
+    ///
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn reverse(&mut self) {
+        self.order.reverse();
+        self._reindex_from(0);
+    }
+
+    /// Rotates the elements in place such that the element at `mid` becomes
+    /// the first element.
+    ///
+    /// - Precondition: `mid` must not be greater than `len()`.
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn rotate_left(&mut self, mid: usize) {
+        self.order.rotate_left(mid);
+        self._reindex_from(0);
+    }
+
+    /// Rotates the elements in place such that the last `k` elements become
+    /// the first elements.
+    ///
+    /// - Precondition: `k` must not be greater than `len()`.
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn rotate_right(&mut self, k: usize) {
+        self.order.rotate_right(k);
+        self._reindex_from(0);
+    }
+
+    ////////////////////
+    ////  Retain      ///
+    ////////////////////
+
+    /// Retains only the elements for which `keep` returns `true`, preserving
+    /// the order of the retained elements.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4]);
+    /// numbers.retain(|e| e % 2 == 0);
+    /// assert_eq!(numbers.elements(), [&2, &4]);
+    /// assert_eq!(numbers.index_of_id(&4), Some(1));
+    /// ```
+    ///
+    /// - Parameter keep: A predicate deciding if an element is kept.
+    /// - Complexity: O(`count`), in a single pass.
+    #[inline]
+    fn retain<P>(&mut self, mut keep: P)
+    where
+        P: FnMut(&E) -> bool,
+    {
+        self._retain_with(|_, element, _| keep(element));
+    }
+
+    /// Retains only the elements for which `keep` returns `true`, passing a
+    /// mutable reference to every element.
+    ///
+    /// - Parameter keep: A predicate deciding if an element is kept, which may
+    ///   mutate the element but MUST NOT change its id.
+    /// - Complexity: O(`count`), in a single pass.
+    #[inline]
+    fn retain_mut<P>(&mut self, mut keep: P)
+    where
+        P: FnMut(&mut E) -> bool,
+    {
+        self._retain_with(|id, element, id_of_element| {
+            keep(&mut ElementMut::new(element, id, id_of_element))
+        });
+    }
+
+    /// Returns an iterator which removes and yields, in order, every element
+    /// for which `extract` returns `true`.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4]);
+    /// let odd = numbers.extract_if(|e| *e % 2 == 1).collect::<Vec<_>>();
+    /// assert_eq!(odd, [1, 3]);
+    /// assert_eq!(numbers.elements(), [&2, &4]);
+    /// ```
+    ///
+    /// - Parameter extract: A predicate deciding if an element is removed, which
+    ///   may mutate the element but MUST NOT change its id.
+    /// - Complexity: O(`count`), in a single pass.
+    #[inline]
+    fn extract_if<P>(&mut self, extract: P) -> IdentifiedVecExtractIf<'_, I, E, F, S, P>
+    where
+        P: FnMut(&mut E) -> bool,
+        S: Default,
+    {
+        IdentifiedVecExtractIf::new(self, extract)
+    }
+
+    ////////////////////
+    ////  Bulk        ///
+    ////////////////////
+
+    /// Removes all elements from the `identified_vec`.
+    ///
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn clear(&mut self) {
+        self.order.clear();
+        self.indices.clear();
+        self.elements.clear();
+    }
+
+    /// Shortens the `identified_vec`, keeping the first `len` elements and
+    /// dropping the rest, has no effect if `len` is greater than or equal to
+    /// the current length.
+    ///
+    /// - Complexity: O(*n*) where *n* is the number of removed elements.
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            _ = self.drain(len..);
+        }
+    }
+
+    /// Splits the `identified_vec` into two at the given index, returning a new
+    /// `identified_vec` with the elements in the range `[at, len)`, using the
+    /// same function to identify elements and a clone of the hash builder.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4]);
+    /// let mut tail = numbers.split_off(2);
+    /// assert_eq!(numbers.elements(), [&1, &2]);
+    /// assert_eq!(tail.elements(), [&3, &4]);
+    /// tail.append(5);
+    /// assert_eq!(tail.index_of_id(&5), Some(2));
+    /// ```
+    ///
+    /// - Parameter at: The position to split at.
+    /// - Returns: The elements from `at` onwards, in order.
+    /// - Precondition: `at` must not be greater than `len()`.
+    /// - Complexity: O(*n*) where *n* is the number of elements split off.
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        F: Clone,
+        S: Clone,
+    {
+        let order = self.order.split_off(at);
+        let mut other = Self::with_capacity_and_hasher(
+            self._id_of_element.clone(),
+            order.len(),
+            self.hasher().clone(),
+        );
+        for (index, id) in order.into_iter().enumerate() {
+            self.indices.remove(&id);
+            let element = self.elements.remove(&id).expect("Element for existing id");
+            other.indices.insert(id.clone(), index);
+            other.elements.insert(id.clone(), element);
+            other.order.push(id);
+        }
+        other
+    }
+
+    /// Removes the elements in the specified `range` from the `identified_vec`,
+    /// returning them, in order, as an owned iterator.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let mut numbers = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e);
+    /// numbers.append_other([1, 2, 3, 4]);
+    /// assert_eq!(numbers.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+    /// assert_eq!(numbers.elements(), [&1, &4]);
+    /// ```
+    ///
+    /// - Parameter range: The range of positions of the elements to remove.
+    /// - Precondition: `range` must be within the bounds of the collection.
+    /// - Complexity: O(`count`)
+    #[inline]
+    fn drain<R>(&mut self, range: R) -> alloc::vec::IntoIter<E>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            core::ops::Bound::Included(start) => *start,
+            core::ops::Bound::Excluded(start) => start + 1,
+            core::ops::Bound::Unbounded => 0,
+        };
+        let drained = self
+            .order
+            .drain(range)
+            .map(|id| {
+                self.indices.remove(&id);
+                self.elements.remove(&id).expect("Element for existing id")
+            })
+            .collect::<Vec<E>>();
+        self._reindex_from(start);
+        drained.into_iter()
+    }
+
+    ////////////////////
+    ////  Set algebra ///
+    ////////////////////
+
+    /// Returns a new `identified_vec` with the elements of `self` followed by
+    /// the elements of `other` whose ids are not in `self`, in their order.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{ConflictResolutionChoice, IsIdentifiedVec, IsIdentifiedVecOf, IdentifiedVecOf};
+    ///
+    /// let local = IdentifiedVecOf::<u32>::from_iter([3, 1, 2]);
+    /// let remote = IdentifiedVecOf::<u32>::from_iter([4, 2, 5]);
+    /// let union = local.union(&remote, ConflictResolutionChoice::ChooseFirst);
+    /// assert_eq!(union.elements(), [&3, &1, &2, &4, &5]);
+    /// ```
+    ///
+    /// - Parameter other: The elements to unite with.
+    /// - Parameter choice: Which element to keep when both collections contain
+    ///   an element with the same id, `ChooseFirst` keeps the one in `self`,
+    ///   `ChooseLast` the one in `other`, the position is that of `self`.
+    /// - Complexity: O(*n* + *m*)
+    #[inline]
+    fn union(mut self, other: &Self, choice: ConflictResolutionChoice) -> Self
+    where
+        E: Clone,
+    {
+        for element in other.iter() {
+            let id = self.id(element);
+            if !self.contains_id(&id) {
+                self._update_value(element.clone(), id);
+                continue;
+            }
+            match choice {
+                ConflictResolutionChoice::ChooseFirst => {}
+                ConflictResolutionChoice::ChooseLast => {
+                    self.elements.insert(id, element.clone());
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns a new `identified_vec` with the elements of `self` followed by
+    /// the elements of `other` whose ids are not in `self`, in their order,
+    /// merging elements present in both.
+    ///
+    /// - Parameter other: The elements to unite with.
+    /// - Parameter merge: Closure merging the element of `other` into the element
+    ///   of `self`, in place, which MUST NOT change its id.
+    /// - Panics: If `merge` changes the id of an element.
+    /// - Complexity: O(*n* + *m*)
+    #[inline]
+    fn union_with<M>(mut self, other: &Self, merge: M) -> Self
+    where
+        E: Clone,
+        M: FnMut(&mut E, E),
+    {
+        self.append_other_merging(other.iter().cloned(), merge);
+        self
+    }
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are also in `other`, in the order of `self`.
+    ///
+    /// - Complexity: O(*n*)
+    #[inline]
+    fn intersection(mut self, other: &Self) -> Self {
+        self._retain_with(|id, _, _| other.contains_id(id));
+        self
+    }
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are not in `other`, in the order of `self`.
+    ///
+    /// - Complexity: O(*n*)
+    #[inline]
+    fn difference(mut self, other: &Self) -> Self {
+        self._retain_with(|id, _, _| !other.contains_id(id));
+        self
+    }
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are not in `other`, followed by the elements of `other` whose ids are
+    /// not in `self`, each in their own order.
+    ///
+    /// - Complexity: O(*n* + *m*)
+    #[inline]
+    fn symmetric_difference(mut self, other: &Self) -> Self
+    where
+        E: Clone,
+    {
+        let keep_other = other
+            .order
+            .iter()
+            .map(|id| !self.contains_id(id))
+            .collect::<Vec<bool>>();
+        self._retain_with(|id, _, _| !other.contains_id(id));
+        self.append_other(
+            other
+                .iter()
+                .zip(keep_other)
+                .filter(|(_, keep)| *keep)
+                .map(|(element, _)| element.clone()),
+        );
+        self
+    }
+
+    /// Returns `true` if the id of every element in `self` is in `other`.
+    ///
+    /// - Complexity: O(*n*)
+    #[inline]
+    fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.order.iter().all(|id| other.contains_id(id))
+    }
+
+    /// Returns `true` if the id of every element in `other` is in `self`.
+    ///
+    /// - Complexity: O(*m*)
+    #[inline]
+    fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no ids in common.
+    ///
+    /// - Complexity: O(min(*n*, *m*))
+    #[inline]
+    fn is_disjoint(&self, other: &Self) -> bool {
+        let (smaller, larger) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        smaller.order.iter().all(|id| !larger.contains_id(id))
+    }
+
+    ////////////////////
+    ////  Diff        ///
+    ////////////////////
+
+    /// Returns the changes which transform `from` into `self`: the removed
+    /// and inserted elements, the elements which moved and the elements
+    /// whose value changed, similar to Swift's `difference(from:)`.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IsIdentifiedVec, IsIdentifiedVecOf, IdentifiedVecOf};
+    ///
+    /// let old = IdentifiedVecOf::<u32>::from_iter([1, 2, 3, 4]);
+    /// let new = IdentifiedVecOf::<u32>::from_iter([4, 1, 5, 3]);
+    /// let diff = new.difference_from(&old);
+    /// assert_eq!(diff.removals().count(), 1);
+    /// assert_eq!(diff.insertions().count(), 1);
+    /// assert_eq!(diff.moves().count(), 1);
+    ///
+    /// let mut synced = old.clone();
+    /// synced.apply(diff).unwrap();
+    /// assert_eq!(synced, new);
+    /// ```
+    ///
+    /// - Parameter from: The source version, `self` being the target.
+    /// - Returns: A diff which, applied to `from` using `apply`, produces `self`.
+    /// - Complexity: O(*n* log *n*) where *n* is the total length of both.
+    #[inline]
+    fn difference_from(&self, from: &Self) -> IdentifiedVecDiff<I, E>
+    where
+        E: PartialEq + Clone,
+    {
+        let mut changes = Vec::new();
+
+        let mut common_targets = Vec::new();
+        let mut common_sources = Vec::new();
+        for (offset, id) in from.order.iter().enumerate() {
+            match self.index_of_id(id) {
+                Some(target) => {
+                    common_sources.push(offset);
+                    common_targets.push(target);
+                }
+                None => changes.push(IdentifiedVecChange::Remove {
+                    offset,
+                    id: id.clone(),
+                    element: from.elements[id].clone(),
+                }),
+            }
+        }
+
+        let mut moved_from = vec![None; self.len()];
+        let stable = longest_increasing_subsequence(&common_targets);
+        for (position, is_stable) in stable.into_iter().enumerate() {
+            if !is_stable {
+                moved_from[common_targets[position]] = Some(common_sources[position]);
+            }
+        }
+
+        let mut updates = Vec::new();
+        for (offset, id) in self.order.iter().enumerate() {
+            let element = &self.elements[id];
+            match from.elements.get(id) {
+                None => changes.push(IdentifiedVecChange::Insert {
+                    offset,
+                    id: id.clone(),
+                    element: element.clone(),
+                }),
+                Some(old) => {
+                    if let Some(source) = moved_from[offset] {
+                        changes.push(IdentifiedVecChange::Move {
+                            from: source,
+                            to: offset,
+                            id: id.clone(),
+                        });
+                    }
+                    if old != element {
+                        updates.push(IdentifiedVecChange::Update {
+                            offset,
+                            id: id.clone(),
+                            old: old.clone(),
+                            new: element.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        changes.append(&mut updates);
+
+        IdentifiedVecDiff::new(changes)
+    }
+
+    /// Applies the changes of `diff`, typically created by `difference_from`,
+    /// to the `identified_vec`.
+    ///
+    /// - Parameter diff: The changes to apply.
+    /// - Returns: `Err` without modifying the `identified_vec` if any removed,
+    ///   moved or updated id is not present, `Error::ExpectedElementNotPresent`,
+    ///   or if any inserted id is already present, `Error::ElementWithSameIDFound`.
+    /// - Complexity: O(*n* log *n*)
+    #[inline]
+    fn apply(&mut self, diff: IdentifiedVecDiff<I, E>) -> Result<(), Error<I>> {
+        for change in diff.changes() {
+            match change {
+                IdentifiedVecChange::Remove { id, .. }
+                | IdentifiedVecChange::Move { id, .. }
+                | IdentifiedVecChange::Update { id, .. } => {
+                    if !self.contains_id(id) {
+                        return Err(Error::ExpectedElementNotPresent { id: id.clone() });
+                    }
+                }
+                IdentifiedVecChange::Insert { id, .. } => {
+                    if let Some(index) = self.index_of_id(id) {
+                        return Err(Error::ElementWithSameIDFound {
+                            id: id.clone(),
+                            index,
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut detached = vec![false; self.len()];
+        let mut placements = Vec::new();
+        for change in diff.into_changes() {
+            match change {
+                IdentifiedVecChange::Remove { id, .. } => {
+                    let index = self.indices.remove(&id).expect("Index of existing id");
+                    detached[index] = true;
+                    self.elements.remove(&id);
+                }
+                IdentifiedVecChange::Move { to, id, .. } => {
+                    detached[self.indices[&id]] = true;
+                    placements.push((to, id));
+                }
+                IdentifiedVecChange::Insert {
+                    offset,
+                    id,
+                    element,
+                    ..
+                } => {
+                    self.indices.insert(id.clone(), offset);
+                    self.elements.insert(id.clone(), element);
+                    placements.push((offset, id));
+                }
+                IdentifiedVecChange::Update { id, new, .. } => {
+                    self.elements.insert(id, new);
+                }
+            }
+        }
+        placements.sort_by_key(|(to, _)| *to);
+
+        let mut stable = core::mem::take(&mut self.order)
+            .into_iter()
+            .zip(detached)
+            .filter_map(|(id, is_detached)| (!is_detached).then_some(id));
+        let mut placements = placements.into_iter().peekable();
+        let mut order = Vec::with_capacity(self.elements.len());
+        loop {
+            match placements.next_if(|(to, _)| *to <= order.len()) {
+                Some((_, id)) => order.push(id),
+                None => match stable.next() {
+                    Some(id) => order.push(id),
+                    None => break,
+                },
+            }
+        }
+        order.extend(placements.map(|(_, id)| id));
+
+        self.order = order;
+        self._reindex_from(0);
+        Ok(())
+    }
+
+    ////////////////////
+    ////  Merge       ///
+    ////////////////////
+
+    /// Three-way merges `self`, "ours", and `theirs`, both edited from their
+    /// common ancestor `base`, combining the insertions, removals, reorders
+    /// and element updates of both sides.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{ConflictResolutionChoice, IsIdentifiedVec, IdentifiedVec};
+    ///
+    /// let identified = |elements: &[(u8, &'static str)]| {
+    ///     let mut identified_vec =
+    ///         IdentifiedVec::<u8, (u8, &'static str), _>::new_identifying_element(|e| e.0);
+    ///     identified_vec.append_other(elements.iter().cloned());
+    ///     identified_vec
+    /// };
+    /// let base = identified(&[(1, "a"), (2, "b"), (3, "c")]);
+    /// let ours = identified(&[(1, "a"), (2, "ours"), (4, "d")]);
+    /// let theirs = identified(&[(3, "c"), (2, "theirs"), (1, "A")]);
+    ///
+    /// let (merged, conflicts) =
+    ///     ours.merge_three_way(&base, &theirs, |_| ConflictResolutionChoice::ChooseLast);
+    /// assert_eq!(merged.elements(), [&(2, "theirs"), &(4, "d"), &(1, "A")]);
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].id, 2);
+    /// ```
+    ///
+    /// - Parameter base: The common ancestor of `self` and `theirs`.
+    /// - Parameter theirs: The other version to merge with.
+    /// - Parameter resolve: Closure settling each conflict.
+    /// - Returns: The merged `identified_vec`, using the function identifying
+    ///   elements and hash builder of `self`, and all the conflicts, in the
+    ///   order they were resolved.
+    /// - Complexity: O(*n*) where *n* is the total length of all three.
+    #[inline]
+    fn merge_three_way<R>(
+        &self,
+        base: &Self,
+        theirs: &Self,
+        mut resolve: R,
+    ) -> (Self, Vec<MergeConflict<I, E>>)
+    where
+        E: PartialEq + Clone,
+        F: Clone,
+        S: Clone,
+        R: FnMut(&MergeConflict<I, E>) -> ConflictResolutionChoice,
+    {
+        let ours = self;
+        let mut conflicts = Vec::new();
+
+        // The merged element of every id, `None` if removed.
+        let mut merged_elements = HashMap::with_hasher(self.hasher().clone());
+        for id in base.order.iter().chain(&ours.order).chain(&theirs.order) {
+            if merged_elements.contains_key(id) {
+                continue;
+            }
+            let (in_base, in_ours, in_theirs) = (base.get(id), ours.get(id), theirs.get(id));
+            let merged = match (in_base, in_ours, in_theirs) {
+                (_, None, None) => Ok(None),
+                (None, Some(o), None) => Ok(Some(o)),
+                (None, None, Some(t)) => Ok(Some(t)),
+                (None, Some(o), Some(t)) if o == t => Ok(Some(o)),
+                (Some(b), Some(o), None) if o == b => Ok(None),
+                (Some(b), None, Some(t)) if t == b => Ok(None),
+                (Some(b), Some(o), Some(t)) if o == t || t == b => Ok(Some(o)),
+                (Some(b), Some(o), Some(t)) if o == b => Ok(Some(t)),
+                _ => Err(MergeConflict {
+                    id: id.clone(),
+                    base: in_base.cloned(),
+                    ours: in_ours.cloned(),
+                    theirs: in_theirs.cloned(),
+                }),
+            };
+            let merged = match merged {
+                Ok(merged) => merged.cloned(),
+                Err(conflict) => {
+                    let merged = match resolve(&conflict) {
+                        ConflictResolutionChoice::ChooseFirst => conflict.ours.clone(),
+                        ConflictResolutionChoice::ChooseLast => conflict.theirs.clone(),
+                    };
+                    conflicts.push(conflict);
+                    merged
+                }
+            };
+            merged_elements.insert(id, merged);
+        }
+
+        let is_reordered = |side: &Self| {
+            side.order
+                .iter()
+                .filter(|id| base.contains_id(id))
+                .ne(base.order.iter().filter(|id| side.contains_id(id)))
+        };
+        let (primary, secondary) = if !is_reordered(ours) && is_reordered(theirs) {
+            (theirs, ours)
+        } else {
+            (ours, theirs)
+        };
+        let is_kept = |id: &I| merged_elements[id].is_some();
+
+        // The ids only kept by `secondary`, keyed by the preceding id kept by
+        // `primary`, `None` for those preceding all of them.
+        let mut placed_after =
+            HashMap::<Option<&I>, Vec<&I>, S>::with_hasher(self.hasher().clone());
+        let mut anchor = None;
+        for id in secondary.order.iter().filter(|id| is_kept(id)) {
+            if primary.contains_id(id) {
+                anchor = Some(id);
+            } else {
+                placed_after.entry(anchor).or_default().push(id);
+            }
+        }
+
+        let mut order = placed_after.remove(&None).unwrap_or_default();
+        for id in primary.order.iter().filter(|id| is_kept(id)) {
+            order.push(id);
+            order.append(&mut placed_after.remove(&Some(id)).unwrap_or_default());
+        }
+
+        let mut merged = Self::with_capacity_and_hasher(
+            self._id_of_element.clone(),
+            order.len(),
+            self.hasher().clone(),
+        );
+        for id in order {
+            let element = merged_elements
+                .remove(id)
+                .flatten()
+                .expect("Element for kept id");
+            merged._update_value(element, id.clone());
+        }
+        (merged, conflicts)
+    }
+}
+
+pub trait ItemsCloned<Element>
+where
+    Element: Clone,
+{
+    fn items(&self) -> Vec<Element>;
+}
+
+impl<I, E, F, S> ItemsCloned<E> for IdentifiedVec<I, E, F, S>
+where
+    E: Clone,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// A read-only collection of clones of the elements contained in this array, as a `Vec<Elements>`.
+    ///
+    /// N.B. that this method is not constant time.
+    ///
+    /// Use `self.elements()` if you are looking for a collection of references.
+    ///
+    /// - Complexity: O(n)
+    #[inline]
+    fn items(&self) -> Vec<E> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<I, E, F, S> IntoIterator for IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    type Item = E;
+    type IntoIter = IdentifiedVecIntoIterator<I, E, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Self::IntoIter::new(self)
+    }
+}
+
+impl<'a, I, E, F, S> IntoIterator for &'a IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    type Item = &'a E;
+    type IntoIter = IdentifiedVecIterator<'a, I, E, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I, E, F, S> Extend<E> for IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// Appends the elements of `iter`, ignoring any element whose id is
+    /// already present, like `append_other`.
+    #[inline]
+    fn extend<It: IntoIterator<Item = E>>(&mut self, iter: It) {
+        self.append_other(iter)
+    }
+}
+
+///////////////////////
+////     Index      ///
+///////////////////////
+impl<I, E, F, S> Index<usize> for IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    type Output = E;
+
+    /// Returns the element at `index`.
+    ///
+    /// - Precondition: `index` must be less than the length, like indexing a `Vec`.
+    #[inline]
+    fn index(&self, index: usize) -> &E {
+        &self.elements[&self.order[index]]
+    }
+}
+
+impl<I, E, F, S> Index<&I> for IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    type Output = E;
+
+    /// Returns the element identified by `id`.
+    ///
+    /// - Precondition: An element identified by `id` must be present, like
+    ///   indexing a `HashMap`.
+    #[inline]
+    fn index(&self, id: &I) -> &E {
+        self.get(id).expect("Expected element with id")
+    }
+}
+
+///////////////////////
+////  Constructors  ///
+///////////////////////
+impl<I, E, F, S> IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher + Clone,
+{
+    /// Constructs a new, empty `IdentifiedVec<I, E, F, S>` with the specified
+    /// `id_of_element` closure, which will use the given hash builder to hash ids.
+    ///
+    /// ```
+    /// extern crate identified_vec;
+    /// use identified_vec::{IdentifiedVecOf, IsIdentifiedVec};
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// type Hasher = BuildHasherDefault<DefaultHasher>;
+    /// let mut numbers = IdentifiedVecOf::<u32, Hasher>::with_hasher(|e| *e, Hasher::default());
+    /// numbers.append(1);
+    /// assert_eq!(numbers.ids(), [1]);
+    /// ```
+    #[inline]
+    pub fn with_hasher(id_of_element: F, hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(id_of_element, 0, hash_builder)
+    }
+
+    /// Constructs a new, empty `IdentifiedVec<I, E, F, S>` with at least the
+    /// specified capacity, using `id_of_element` closure to identify elements and
+    /// the given hash builder to hash ids.
+    #[inline]
+    pub fn with_capacity_and_hasher(id_of_element: F, capacity: usize, hash_builder: S) -> Self {
+        Self {
+            order: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity_and_hasher(capacity, hash_builder.clone()),
+            elements: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+            _id_of_element: id_of_element,
+        }
+    }
+
+    /// Returns a reference to the `identified_vec`'s `BuildHasher`.
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        self.elements.hasher()
+    }
+}
+
+impl<I, E, F, S> Debug for IdentifiedVec<I, E, F, S>
+where
+    E: Debug,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("IdentifiedVec")
+            .field("order", &self.order)
+            .field("elements", &self.elements)
+            .finish_non_exhaustive()
+    }
+}
+
+///////////////////////
+////      Eq        ///
+///////////////////////
+impl<I, E, F, S> PartialEq for IdentifiedVec<I, E, F, S>
+where
+    E: PartialEq,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.elements() == other.elements()
+    }
+}
+
+impl<I, E, F, S> PartialEq<[E]> for IdentifiedVec<I, E, F, S>
+where
+    E: PartialEq,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &[E]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(lhs, rhs)| lhs == rhs)
+    }
+}
+
+impl<I, E, F, S> PartialEq<Vec<E>> for IdentifiedVec<I, E, F, S>
+where
+    E: PartialEq,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Vec<E>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<I, E, F, S, const N: usize> PartialEq<[E; N]> for IdentifiedVec<I, E, F, S>
+where
+    E: PartialEq,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &[E; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<I, E, F, S> Eq for IdentifiedVec<I, E, F, S>
+where
+    E: Eq,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+}
+
+///////////////////////
+////      Hash      ///
+///////////////////////
+impl<I, E, F, S> Hash for IdentifiedVec<I, E, F, S>
+where
+    E: Hash,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.elements().hash(state);
+    }
+}
+
+///////////////////////
+////    SERDE       ///
+///////////////////////
+#[cfg(feature = "serde")]
+impl<I, E, F, S> Serialize for IdentifiedVec<I, E, F, S>
+where
+    E: Serialize,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+///////////////////////
+////      Display   ///
+///////////////////////
+impl<I, E, F, S> Display for IdentifiedVec<I, E, F, S>
+where
+    E: Debug,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.elements().fmt(f)
+    }
+}
+
+///////////////////////
+////    PRIVATE     ///
+///////////////////////
+impl<I, E, F, S> IdentifiedVec<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// Next index for element appended
+    #[inline]
+    fn end_index(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the I of an E
+    #[inline]
+    fn id(&self, of: &E) -> I {
+        (self._id_of_element)(of)
+    }
+
+    /// Inserting I at an index, returning if it did, if not, the index of the existing.
+    #[cfg(not(tarpaulin_include))] // false negative
+    #[inline]
+    fn _insert_id_at(&mut self, id: I, index: usize) -> (bool, usize) {
+        match self.index_of_id(&id) {
+            Some(existing) => (false, existing),
+            None => {
+                self.order.insert(index, id.clone());
+                self.indices.insert(id, index);
+                self._reindex_from(index + 1);
+                (true, index)
+            }
+        }
+    }
+
+    /// Appends the elements of `other` to a `identified_vec` being constructed,
+    /// merging those with the id of an existing element using `merge`, which
+    /// takes the existing element out. Only used by constructors, which drop the
+    /// `identified_vec`, together with that element, if `merge` fails or panics.
+    ///
+    /// - Panics: If the merged element does not have the id of the existing element.
+    #[inline]
+    fn _try_append_other_merging<It, M, Er>(&mut self, other: It, mut merge: M) -> Result<(), Er>
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(Conflict<&I, E>) -> Result<E, Er>,
+    {
+        for (index, element) in other.into_iter().enumerate() {
+            let id = self.id(&element);
+            match self.elements.remove(&id) {
+                Some(existing) => {
+                    let merged = merge(Conflict {
+                        index,
+                        existing_index: self.indices[&id],
+                        id: &id,
+                        existing,
+                        new: element,
+                    })?;
+                    assert_eq!(
+                        self.id(&merged),
+                        id,
+                        "The merged element must match the identity of the original"
+                    );
+                    self.elements.insert(id, merged);
+                }
+                None => {
+                    self._update_value(element, id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Retains only the elements for which `keep`, given their id, the element
+    /// and the `id_of_element` function, returns `true`, in a single pass.
+    #[inline]
+    fn _retain_with<P>(&mut self, mut keep: P)
+    where
+        P: FnMut(&I, &mut E, &F) -> bool,
+    {
+        let id_of_element = &self._id_of_element;
+        let elements = &mut self.elements;
+        let indices = &mut self.indices;
+        let mut index = 0;
+        self.order.retain(|id| {
+            let element = elements.get_mut(id).expect("Element for existing id");
+            if keep(id, element, id_of_element) {
+                *indices.get_mut(id).expect("Index of existing id") = index;
+                index += 1;
+                true
+            } else {
+                indices.remove(id);
+                elements.remove(id);
+                false
+            }
+        });
+    }
+
+    /// Updates the stored position of every id in `order` starting at `start`,
+    /// must be called after ids have been inserted into or removed from `order`.
+    #[inline]
+    fn _reindex_from(&mut self, start: usize) {
+        for (index, id) in self.order.iter().enumerate().skip(start) {
+            *self.indices.get_mut(id).expect("Index of existing id") = index;
+        }
+    }
+
+    #[inline]
+    fn _update_value(&mut self, element: E, for_key: I) -> Option<E> {
+        let value = element;
+        let key = for_key;
+
+        let maybe_old = self.elements.insert(key.clone(), value);
+
+        if maybe_old.is_none() {
+            self.indices.insert(key.clone(), self.order.len());
+            self.order.push(key);
+        }
+        maybe_old
     }
 
     #[inline]
@@ -843,16 +2327,17 @@ where
             self.elements.insert(id.clone(), value);
             return (None, offset);
         }
-        let old = self.elements.remove(&id).expect("existing element");
-        self.elements.insert(id, value);
-        return (Some(old), offset);
+        let old = self.elements.insert(id, value).expect("existing element");
+        (Some(old), offset)
     }
 }
 
-impl<ID, Element> IdentifiedVec<ID, Element>
+impl<ID, Element, F, S> IdentifiedVec<ID, Element, F, S>
 where
     ID: Eq + Hash + Clone + Debug,
     Element: Eq + Debug,
+    F: Fn(&Element) -> ID,
+    S: BuildHasher,
 {
     /// Try append a new unique member to the end of the `identified_vec`, if the `identified_vec` already contains the Value or ID a Error will be returned.
     ///
@@ -863,14 +2348,17 @@ where
     /// - Complexity: The operation is expected to perform O(1) copy, hash, and compare operations on
     ///   the `ID` type, if it implements high-quality hashing.
     #[inline]
-    pub fn try_append_unique_element(&mut self, element: Element) -> Result<(bool, usize), Error> {
+    pub fn try_append_unique_element(
+        &mut self,
+        element: Element,
+    ) -> Result<(bool, usize), Error<ID>> {
         let id = self.id(&element);
 
-        if let Some(value) = self.get(&id) {
-            if value == &element {
-                return Err(Error::ElementWithSameValueFound(format!("{:?}", value)));
+        if let Some(index) = self.index_of_id(&id) {
+            if self.elements[&id] == element {
+                return Err(Error::ElementWithSameValueFound { id, index });
             } else {
-                return Err(Error::ElementWithSameIDFound(format!("{:?}", id)));
+                return Err(Error::ElementWithSameIDFound { id, index });
             }
         }
 
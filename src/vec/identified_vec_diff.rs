@@ -0,0 +1,119 @@
+use alloc::{vec, vec::Vec};
+
+/// A single change between two versions of an `IdentifiedVec`, part of an
+/// `IdentifiedVecDiff`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdentifiedVecChange<ID, E> {
+    /// The element identified by `id` at `offset` in the source is not
+    /// present in the target.
+    Remove { offset: usize, id: ID, element: E },
+
+    /// The element identified by `id` is not present in the source and is
+    /// at `offset` in the target.
+    Insert { offset: usize, id: ID, element: E },
+
+    /// The element identified by `id` is present in both, but moved from
+    /// offset `from` in the source to offset `to` in the target.
+    Move { from: usize, to: usize, id: ID },
+
+    /// The element identified by `id` is present in both, at `offset` in the
+    /// target, but its value changed from `old` to `new`.
+    Update {
+        offset: usize,
+        id: ID,
+        old: E,
+        new: E,
+    },
+}
+
+/// The ordered changes which transform one version of an `IdentifiedVec`
+/// into another, similar to Swift's `CollectionDifference` with inferred
+/// moves.
+///
+/// Created by `IsIdentifiedVec::difference_from` and applied using
+/// `IsIdentifiedVec::apply`. Changes are stored as removals in ascending
+/// source offset, followed by moves and insertions in ascending target
+/// offset, followed by updates in ascending target offset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdentifiedVecDiff<ID, E> {
+    changes: Vec<IdentifiedVecChange<ID, E>>,
+}
+
+impl<ID, E> IdentifiedVecDiff<ID, E> {
+    pub(crate) fn new(changes: Vec<IdentifiedVecChange<ID, E>>) -> Self {
+        Self { changes }
+    }
+
+    /// All the changes, in the order described in the type level documentation.
+    pub fn changes(&self) -> &[IdentifiedVecChange<ID, E>] {
+        &self.changes
+    }
+
+    /// Consumes the diff, returning all its changes.
+    pub fn into_changes(self) -> Vec<IdentifiedVecChange<ID, E>> {
+        self.changes
+    }
+
+    /// Returns `true` if the source and the target are equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes which are `IdentifiedVecChange::Remove`.
+    pub fn removals(&self) -> impl Iterator<Item = &IdentifiedVecChange<ID, E>> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, IdentifiedVecChange::Remove { .. }))
+    }
+
+    /// The changes which are `IdentifiedVecChange::Insert`.
+    pub fn insertions(&self) -> impl Iterator<Item = &IdentifiedVecChange<ID, E>> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, IdentifiedVecChange::Insert { .. }))
+    }
+
+    /// The changes which are `IdentifiedVecChange::Move`.
+    pub fn moves(&self) -> impl Iterator<Item = &IdentifiedVecChange<ID, E>> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, IdentifiedVecChange::Move { .. }))
+    }
+
+    /// The changes which are `IdentifiedVecChange::Update`.
+    pub fn updates(&self) -> impl Iterator<Item = &IdentifiedVecChange<ID, E>> {
+        self.changes
+            .iter()
+            .filter(|change| matches!(change, IdentifiedVecChange::Update { .. }))
+    }
+}
+
+/// Returns, for every value, whether it is part of a longest strictly
+/// increasing subsequence of `values`, used to tell which elements present
+/// in both versions have not moved.
+///
+/// - Complexity: O(*n* log *n*)
+pub(crate) fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // `tails[k]` is the position in `values` of the smallest tail of all
+    // increasing subsequences of length `k + 1`.
+    let mut tails = Vec::<usize>::new();
+    let mut predecessors = vec![None; values.len()];
+    for (position, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|tail| values[*tail] < *value);
+        if length > 0 {
+            predecessors[position] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(position);
+        } else {
+            tails[length] = position;
+        }
+    }
+    let mut is_member = vec![false; values.len()];
+    let mut position = tails.last().copied();
+    while let Some(current) = position {
+        is_member[current] = true;
+        position = predecessors[current];
+    }
+    is_member
+}
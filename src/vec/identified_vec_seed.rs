@@ -0,0 +1,89 @@
+use crate::collections::RandomState;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed};
+use serde::{Deserialize, Deserializer};
+
+use crate::{IdentifiedVec, IdentifiedVecOfSerdeFailure, IsIdentifiedVec};
+
+/// A `DeserializeSeed` carrying the function identifying elements, allowing
+/// any `IdentifiedVec` to be deserialized from a sequence of elements.
+///
+/// Fails with `IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex` if two
+/// elements have the same id.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{IdentifiedVecSeed, IsIdentifiedVec};
+/// use serde::de::DeserializeSeed;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"["Blob", "Blob, Jr."]"#);
+/// let names = IdentifiedVecSeed::<usize, String, _>::new(|name: &String| name.len())
+///     .deserialize(&mut deserializer)
+///     .unwrap();
+/// assert_eq!(names.ids(), [4, 9]);
+/// ```
+pub struct IdentifiedVecSeed<I, E, F, S = RandomState> {
+    id_of_element: F,
+    _marker: PhantomData<Deserialized<I, E, S>>,
+}
+
+/// The types deserialized, besides the id function, `fn` makes the seed
+/// `Send` and `Sync` regardless of them.
+type Deserialized<I, E, S> = fn() -> (I, E, S);
+
+impl<I, E, F, S> IdentifiedVecSeed<I, E, F, S>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher,
+{
+    /// Creates a seed deserializing an `IdentifiedVec` identifying its
+    /// elements using `id_of_element`.
+    pub fn new(id_of_element: F) -> Self {
+        Self {
+            id_of_element,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, I, E, F, S> DeserializeSeed<'de> for IdentifiedVecSeed<I, E, F, S>
+where
+    E: Deserialize<'de>,
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher + Default,
+{
+    type Value = IdentifiedVec<I, E, F, S>;
+
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let elements = Vec::<E>::deserialize(deserializer)?;
+        from_unique_elements(elements, self.id_of_element)
+    }
+}
+
+/// Creates an `IdentifiedVec` from deserialized `elements`, failing with
+/// `IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex` if two elements
+/// have the same id.
+pub(crate) fn from_unique_elements<I, E, F, S, Er>(
+    elements: Vec<E>,
+    id_of_element: F,
+) -> Result<IdentifiedVec<I, E, F, S>, Er>
+where
+    I: Eq + Hash + Clone + Debug,
+    F: Fn(&E) -> I,
+    S: BuildHasher + Default,
+    Er: de::Error,
+{
+    IdentifiedVec::try_from_iter_select_unique_ids_with(elements, id_of_element, |conflict| {
+        Err(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(
+            conflict.index,
+        ))
+    })
+    .map_err(de::Error::custom)
+}
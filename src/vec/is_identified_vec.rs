@@ -1,15 +1,31 @@
+use crate::iterators::identified_vec_extract_if::IdentifiedVecExtractIf;
+use crate::iterators::identified_vec_iter_mut::IdentifiedVecIterMut;
 use crate::iterators::identified_vec_iterator::IdentifiedVecIterator;
-use crate::{ConflictResolutionChoice, Error};
-use std::fmt::Debug;
-use std::hash::Hash;
+use crate::{
+    Conflict, ConflictResolutionChoice, ElementMut, Entry, Error, IdentifiedVecDiff, MergeConflict,
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::{BuildHasher, Hash};
+use core::ops::RangeBounds;
 
 pub trait IsIdentifiedVec<Element, ID>: Sized
 where
     ID: Eq + Hash + Clone + Debug,
 {
+    /// The type of the function which extracts the `ID` of an `Element`, for
+    /// `IdentifiedVec` this is either a function pointer or a closure.
+    type IdOfElement: Fn(&Element) -> ID;
+
+    /// The type of the hash builder used to hash ids, for `IdentifiedVec`
+    /// this defaults to `RandomState`.
+    type Hasher: BuildHasher;
+
     /// Constructs a new, empty `IdentifiedVec<I, E>` with the specified
     /// `id_of_element` closure
-    fn new_identifying_element(id_of_element: fn(&Element) -> ID) -> Self;
+    fn new_identifying_element(id_of_element: Self::IdOfElement) -> Self
+    where
+        Self::Hasher: Default;
 
     /// Creates a new `identified_vec` from the elements in the given sequence, using a combining closure to
     /// determine the element for any elements with duplicate identity.
@@ -23,25 +39,72 @@ where
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
     ///   - id_of_element: The function which extracts the identifier for an element,
-    ///   - combine: Closure trying to combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
+    ///   - combine: Closure trying to combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
     ///   implements high-quality hashing.
-    fn try_from_iter_select_unique_ids_with<Er, It>(
+    fn try_from_iter_select_unique_ids_with<Er, It, C>(
         elements: It,
-        id_of_element: fn(&Element) -> ID,
-        combine: fn((usize, &Element, &Element)) -> Result<ConflictResolutionChoice, Er>,
+        id_of_element: Self::IdOfElement,
+        combine: C,
     ) -> Result<Self, Er>
     where
-        It: IntoIterator<Item = Element>;
+        C: FnMut(Conflict<&ID, &Element>) -> Result<ConflictResolutionChoice, Er>,
+        It: IntoIterator<Item = Element>,
+        Self::Hasher: Default;
 
-    fn from_iter_select_unique_ids_with<It>(
+    fn from_iter_select_unique_ids_with<It, C>(
         elements: It,
-        id_of_element: fn(&Element) -> ID,
-        combine: fn((usize, &Element, &Element)) -> ConflictResolutionChoice,
+        id_of_element: Self::IdOfElement,
+        combine: C,
     ) -> Self
     where
-        It: IntoIterator<Item = Element>;
+        C: FnMut(Conflict<&ID, &Element>) -> ConflictResolutionChoice,
+        It: IntoIterator<Item = Element>,
+        Self::Hasher: Default;
+
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate identity into a new element.
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - id_of_element: The function which extracts the identifier for an element,
+    ///   - merge: Closure trying to merge elements with duplicate ids, given the `Conflict` into a new element, which must have the same id, or `Err` if you prefer.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`,
+    ///   or the first `Err` of `merge`.
+    /// - Warning: `merge` takes ownership of both elements of the `Conflict`, so if it returns `Err`, or
+    ///   panics, they are dropped, together with every element consumed from `elements` so far.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
+    ///   implements high-quality hashing.
+    fn try_from_iter_merge_unique_ids_with<Er, It, M>(
+        elements: It,
+        id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Result<Self, Er>
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&ID, Element>) -> Result<Element, Er>,
+        Self::Hasher: Default;
+
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate identity into a new element.
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - id_of_element: The function which extracts the identifier for an element,
+    ///   - merge: Closure merging elements with duplicate ids, given the `Conflict` into a new element, which must have the same id.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
+    ///   implements high-quality hashing.
+    fn from_iter_merge_unique_ids_with<It, M>(
+        elements: It,
+        id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Self
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&ID, Element>) -> Element,
+        Self::Hasher: Default;
 
     ///The ids contained in this `identified_vec`, as an `Vec<ID>` (cloned)
     fn ids(&self) -> Vec<ID>;
@@ -49,6 +112,11 @@ where
     /// Returns the number of elements in the `identified_vec`, also referred to as its 'length'.
     fn len(&self) -> usize;
 
+    /// Returns `true` if the `identified_vec` contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns the index for the given id.
     ///
     /// If an element identified by the given id is found in the `identified_vec`, this method returns an index
@@ -103,6 +171,25 @@ where
     /// Returns a reference to the element at index if found, else `None`.
     fn get_at_index(&self, index: usize) -> Option<&Element>;
 
+    /// Returns a mutable reference to the element corresponding to the `id`,
+    /// if found, else `None`.
+    ///
+    /// The returned `ElementMut` panics on drop if the id of the element
+    /// has been changed.
+    fn get_mut(&mut self, id: &ID) -> Option<ElementMut<'_, ID, Element>>;
+
+    /// Returns a mutable reference to the element at index if found, else `None`.
+    ///
+    /// The returned `ElementMut` panics on drop if the id of the element
+    /// has been changed.
+    fn get_at_index_mut(&mut self, index: usize) -> Option<ElementMut<'_, ID, Element>>;
+
+    /// Returns the `Entry` of `id`, occupied if an element with `id` is present,
+    /// else vacant, for in place manipulation.
+    ///
+    /// - Complexity: Expected to be O(1) on average, if `ID` implements high-quality hashing.
+    fn entry(&mut self, id: ID) -> Entry<'_, ID, Element, Self::IdOfElement, Self::Hasher>;
+
     /// Append a new member to the end of the `identified_vec`, if the `identified_vec` doesn't already contain it.
     ///
     /// - Parameter item: The element to add to the `identified_vec`.
@@ -123,6 +210,21 @@ where
     where
         It: IntoIterator<Item = Element>;
 
+    /// Append the contents of an iterator to the end of the set, merging elements which are already
+    /// members into the existing ones, in place, keeping their position.
+    ///
+    /// - Parameter elements: A finite sequence of elements to append.
+    /// - Parameter merge: Closure merging the new element into the existing element, which
+    ///   MUST NOT change its id. If `merge` panics the existing element stays in the
+    ///   `identified_vec`, with whatever changes `merge` made before panicking.
+    /// - Panics: If `merge` changes the id of the existing element.
+    /// - Complexity: The operation is expected to perform amortized O(1) copy, hash, and compare
+    ///   operations on the `Element` type, if it implements high-quality hashing.
+    fn append_other_merging<It, M>(&mut self, other: It, merge: M)
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(&mut Element, Element);
+
     /// Adds the given element to the `identified_vec` unconditionally, either appending it to the `identified_vec``, or
     /// replacing an existing value if it's already present.
     ///
@@ -141,6 +243,14 @@ where
     /// - Returns: The original element that was replaced.
     fn update_at(&mut self, element: Element, index: usize) -> Element;
 
+    /// Replace the member at the given index with a new value of the same identity,
+    /// without panicking.
+    ///
+    /// - Returns: The original element that was replaced, or `Error::IndexOutOfBounds` if
+    ///   `index` is not a valid index, or `Error::IdMismatch` if `item` does not match the
+    ///   identity of the original value.
+    fn try_update_at(&mut self, element: Element, index: usize) -> Result<Element, Error<ID>>;
+
     fn update_with<F>(&mut self, id: &ID, mutate: F) -> bool
     where
         F: FnMut(&mut Element);
@@ -163,6 +273,13 @@ where
     ///   to make room in the storage identified_vec to add the inserted element.)
     fn insert(&mut self, element: Element, at: usize) -> (bool, usize);
 
+    /// Insert a new member to this identified_vec at the specified index, if the identified_vec doesn't already contain
+    /// it, without panicking.
+    ///
+    /// - Returns: Like `insert`, a pair `(inserted, index)`, or `Error::IndexOutOfBounds` if
+    ///   the element is not already present and `at` is greater than the length.
+    fn try_insert(&mut self, element: Element, at: usize) -> Result<(bool, usize), Error<ID>>;
+
     /// Adds the given element into the set unconditionally, either inserting it at the specified
     /// index, or replacing an existing value if it's already present.
     ///
@@ -181,7 +298,7 @@ where
     /// - Returns: A Result with either the original element that was replaced by this operation, or a Error, `Error::ExpectedElementNotPresent`, specifying that the expected element is not present within the collection.
     /// - Complexity: The operation is expected to perform amortized O(1) copy, hash, and compare
     ///   operations on the `ID` type, if it implements high-quality hashing.
-    fn try_update(&mut self, element: Element) -> Result<Element, Error>;
+    fn try_update(&mut self, element: Element) -> Result<Element, Error<ID>>;
 
     /////////////
     // Remove  //
@@ -222,7 +339,7 @@ where
     ///
     /// - Parameter id: The id of the element to be removed from the `identified_vec`.
     /// - Returns: The element that was removed, or `None` if the element was not present in the array.
-    /// - Complexity: O(`count`)
+    /// - Complexity: O(`count`) in general, O(1) when removing the last element.
     fn remove_by_id(&mut self, id: &ID) -> Option<Element>;
 
     /// Removes the given element from the `identified_vec`.
@@ -247,14 +364,34 @@ where
     /// - Complexity: O(`count`)
     fn remove_at(&mut self, index: usize) -> Element;
 
-    /// Removes all the elements at the specified `offsets` from the `identified_vec`.
+    /// Removes and returns the element at the specified position, without panicking.
+    ///
+    /// - Returns: The removed element, or `Error::IndexOutOfBounds` if `index` is not a
+    ///   valid index.
+    fn try_remove_at(&mut self, index: usize) -> Result<Element, Error<ID>>;
+
+    /// Removes all the elements at the specified `offsets` from the `identified_vec`,
+    /// the `offsets` need not be sorted nor unique.
     ///
     /// - Parameter offsets: The offsets of all elements to be removed.
-    /// - Complexity: O(*n*) where *n* is the length of the `identified_vec`.
+    /// - Precondition: Every offset must be a valid index, else no element is removed.
+    /// - Complexity: O(*n* + *k*) where *n* is the length of the `identified_vec` and *k*
+    ///   the number of `offsets`.
     fn remove_at_offsets<It>(&mut self, offsets: It)
     where
         It: IntoIterator<Item = usize>;
 
+    /// Removes all the elements at the specified `offsets` from the `identified_vec`,
+    /// without panicking, the `offsets` need not be sorted nor unique.
+    ///
+    /// - Returns: `Error::IndexOutOfBounds` for the first offset which is not a valid index,
+    ///   in which case no element is removed.
+    /// - Complexity: O(*n* + *k*) where *n* is the length of the `identified_vec` and *k*
+    ///   the number of `offsets`.
+    fn try_remove_at_offsets<It>(&mut self, offsets: It) -> Result<(), Error<ID>>
+    where
+        It: IntoIterator<Item = usize>;
+
     /// Try append a new member to the end of the `identified_vec`, if the `identified_vec` already contains the element a Error will be returned.
     ///
     /// - Parameter item: The element to add to the `identified_vec`.
@@ -263,7 +400,317 @@ where
     ///   `identified_vec`. If the given ID pre-exists within the collection the function call returns `Error::ElementWithSameIDFound`.
     /// - Complexity: The operation is expected to perform O(1) copy, hash, and compare operations on
     ///   the `ID` type, if it implements high-quality hashing.
-    fn try_append_new(&mut self, element: Element) -> Result<(bool, usize), Error>;
+    fn try_append_new(&mut self, element: Element) -> Result<(bool, usize), Error<ID>>;
+
+    fn iter(&self) -> IdentifiedVecIterator<'_, ID, Element, Self::Hasher>;
+
+    /// Returns an iterator over mutable references to the elements, in order.
+    ///
+    /// The yielded `ElementMut`s panic on drop if the id of the element
+    /// has been changed.
+    fn iter_mut(&mut self) -> IdentifiedVecIterMut<'_, ID, Element>;
+
+    /// A collection of mutable references to the elements contained in this
+    /// `identified_vec`, in order, the mutable counterpart of `elements()`.
+    fn values_mut(&mut self) -> Vec<ElementMut<'_, ID, Element>>;
+
+    /// Sorts the `identified_vec` in place, preserving the relative order of
+    /// equal elements.
+    ///
+    /// Only the order of the ids is changed, no element is moved or cloned.
+    ///
+    /// - Complexity: O(*n* log *n*)
+    fn sort(&mut self)
+    where
+        Element: Ord;
+
+    /// Sorts the `identified_vec` in place using the given `compare` function,
+    /// preserving the relative order of equal elements.
+    ///
+    /// - Parameter compare: A function returning the ordering of two elements.
+    /// - Complexity: O(*n* log *n*)
+    fn sort_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&Element, &Element) -> core::cmp::Ordering;
+
+    /// Sorts the `identified_vec` in place using the key extracted by `key`,
+    /// preserving the relative order of elements with equal keys.
+    ///
+    /// - Parameter key: A function returning the key to sort an element by.
+    /// - Complexity: O(*m* *n* log *n*), where the key function is O(*m*).
+    fn sort_by_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&Element) -> K,
+        K: Ord;
+
+    /// Sorts the `identified_vec` in place using the given `compare` function,
+    /// without preserving the relative order of equal elements.
+    ///
+    /// - Parameter compare: A function returning the ordering of two elements.
+    /// - Complexity: O(*n* log *n*)
+    fn sort_unstable_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&Element, &Element) -> core::cmp::Ordering;
+
+    /// Sorts the `identified_vec` in place using the key extracted by `key`,
+    /// which is called at most once per element.
+    ///
+    /// - Parameter key: A function returning the key to sort an element by.
+    /// - Complexity: O(*m* *n* + *n* log *n*), where the key function is O(*m*).
+    fn sort_by_cached_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&Element) -> K,
+        K: Ord;
+
+    /// Sorts the `identified_vec` in place by the ids of its elements.
+    ///
+    /// - Complexity: O(*n* log *n*)
+    fn sort_by_id(&mut self)
+    where
+        ID: Ord;
+
+    /// Moves the element at `from` so that it ends up at position `to`,
+    /// shifting the elements in between.
+    ///
+    /// - Parameter from: The current position of the element to move.
+    /// - Parameter to: The position the element will have after the move.
+    /// - Precondition: Both `from` and `to` must be valid indices of the collection.
+    /// - Complexity: O(`count`)
+    fn move_element(&mut self, from: usize, to: usize);
+
+    /// Moves all the elements at the specified `offsets` to the specified
+    /// destination offset, preserving their relative order, like Swift's
+    /// `move(fromOffsets:toOffset:)`.
+    ///
+    /// - Parameter offsets: The offsets of all elements to be moved.
+    /// - Parameter to_offset: The offset, in the collection before the move, to
+    ///   insert the moved elements before, which may be equal to `len()`.
+    /// - Precondition: All offsets must be valid indices and `to_offset` must
+    ///   not be greater than `len()`.
+    /// - Complexity: O(*n*) where *n* is the length of the `identified_vec`.
+    fn move_from_offsets<It>(&mut self, offsets: It, to_offset: usize)
+    where
+        It: IntoIterator<Item = usize>;
+
+    /// Swaps the elements at positions `i` and `j`.
+    ///
+    /// - Precondition: Both `i` and `j` must be valid indices of the collection.
+    /// - Complexity: O(1)
+    fn swap_at(&mut self, i: usize, j: usize);
+
+    /// Swaps the positions of the elements identified by `lhs` and `rhs`.
+    ///
+    /// - Returns: `true` if both elements were present and got swapped, else `false`.
+    /// - Complexity: O(1)
+    fn swap_ids(&mut self, lhs: &ID, rhs: &ID) -> bool;
 
-    fn iter(&self) -> IdentifiedVecIterator<ID, Element>;
+    /// Reverses the order of the elements in place.
+    ///
+    /// - Complexity: O(`count`)
+    fn reverse(&mut self);
+
+    /// Rotates the elements in place such that the element at `mid` becomes
+    /// the first element.
+    ///
+    /// - Precondition: `mid` must not be greater than `len()`.
+    /// - Complexity: O(`count`)
+    fn rotate_left(&mut self, mid: usize);
+
+    /// Rotates the elements in place such that the last `k` elements become
+    /// the first elements.
+    ///
+    /// - Precondition: `k` must not be greater than `len()`.
+    /// - Complexity: O(`count`)
+    fn rotate_right(&mut self, k: usize);
+
+    /// Retains only the elements for which `keep` returns `true`, preserving
+    /// the order of the retained elements.
+    ///
+    /// - Parameter keep: A predicate deciding if an element is kept.
+    /// - Complexity: O(`count`), in a single pass.
+    fn retain<P>(&mut self, keep: P)
+    where
+        P: FnMut(&Element) -> bool;
+
+    /// Retains only the elements for which `keep` returns `true`, passing a
+    /// mutable reference to every element.
+    ///
+    /// - Parameter keep: A predicate deciding if an element is kept, which may
+    ///   mutate the element but MUST NOT change its id.
+    /// - Complexity: O(`count`), in a single pass.
+    fn retain_mut<P>(&mut self, keep: P)
+    where
+        P: FnMut(&mut Element) -> bool;
+
+    /// Returns an iterator which removes and yields, in order, every element
+    /// for which `extract` returns `true`.
+    ///
+    /// If the iterator is dropped before being exhausted the elements not yet
+    /// visited are kept, if it is leaked the `identified_vec` is left empty.
+    ///
+    /// - Parameter extract: A predicate deciding if an element is removed, which
+    ///   may mutate the element but MUST NOT change its id.
+    /// - Complexity: O(`count`), in a single pass.
+    fn extract_if<P>(
+        &mut self,
+        extract: P,
+    ) -> IdentifiedVecExtractIf<'_, ID, Element, Self::IdOfElement, Self::Hasher, P>
+    where
+        P: FnMut(&mut Element) -> bool,
+        Self::Hasher: Default;
+
+    /// Removes all elements from the `identified_vec`.
+    ///
+    /// - Complexity: O(`count`)
+    fn clear(&mut self);
+
+    /// Shortens the `identified_vec`, keeping the first `len` elements and
+    /// dropping the rest, has no effect if `len` is greater than or equal to
+    /// the current length.
+    ///
+    /// - Complexity: O(*n*) where *n* is the number of removed elements.
+    fn truncate(&mut self, len: usize);
+
+    /// Splits the `identified_vec` into two at the given index, returning a new
+    /// `identified_vec` with the elements in the range `[at, len)`, using the
+    /// same function to identify elements and a clone of the hash builder.
+    ///
+    /// - Parameter at: The position to split at.
+    /// - Returns: The elements from `at` onwards, in order.
+    /// - Precondition: `at` must not be greater than `len()`.
+    /// - Complexity: O(*n*) where *n* is the number of elements split off.
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        Self::IdOfElement: Clone,
+        Self::Hasher: Clone;
+
+    /// Removes the elements in the specified `range` from the `identified_vec`,
+    /// returning them, in order, as an owned iterator.
+    ///
+    /// - Parameter range: The range of positions of the elements to remove.
+    /// - Precondition: `range` must be within the bounds of the collection.
+    /// - Complexity: O(`count`)
+    fn drain<R>(&mut self, range: R) -> alloc::vec::IntoIter<Element>
+    where
+        R: RangeBounds<usize>;
+
+    /// Returns a new `identified_vec` with the elements of `self` followed by
+    /// the elements of `other` whose ids are not in `self`, in their order.
+    ///
+    /// - Parameter other: The elements to unite with.
+    /// - Parameter choice: Which element to keep when both collections contain
+    ///   an element with the same id, `ChooseFirst` keeps the one in `self`,
+    ///   `ChooseLast` the one in `other`, the position is that of `self`.
+    /// - Complexity: O(*n* + *m*)
+    fn union(self, other: &Self, choice: ConflictResolutionChoice) -> Self
+    where
+        Element: Clone;
+
+    /// Returns a new `identified_vec` with the elements of `self` followed by
+    /// the elements of `other` whose ids are not in `self`, in their order,
+    /// merging elements present in both.
+    ///
+    /// - Parameter other: The elements to unite with.
+    /// - Parameter merge: Closure merging the element of `other` into the element
+    ///   of `self`, in place, which MUST NOT change its id.
+    /// - Panics: If `merge` changes the id of an element.
+    /// - Complexity: O(*n* + *m*)
+    fn union_with<M>(self, other: &Self, merge: M) -> Self
+    where
+        Element: Clone,
+        M: FnMut(&mut Element, Element);
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are also in `other`, in the order of `self`.
+    ///
+    /// - Complexity: O(*n*)
+    fn intersection(self, other: &Self) -> Self;
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are not in `other`, in the order of `self`.
+    ///
+    /// - Complexity: O(*n*)
+    fn difference(self, other: &Self) -> Self;
+
+    /// Returns a new `identified_vec` with the elements of `self` whose ids
+    /// are not in `other`, followed by the elements of `other` whose ids are
+    /// not in `self`, each in their own order.
+    ///
+    /// - Complexity: O(*n* + *m*)
+    fn symmetric_difference(self, other: &Self) -> Self
+    where
+        Element: Clone;
+
+    /// Returns `true` if the id of every element in `self` is in `other`.
+    ///
+    /// - Complexity: O(*n*)
+    fn is_subset(&self, other: &Self) -> bool;
+
+    /// Returns `true` if the id of every element in `other` is in `self`.
+    ///
+    /// - Complexity: O(*m*)
+    fn is_superset(&self, other: &Self) -> bool;
+
+    /// Returns `true` if `self` and `other` have no ids in common.
+    ///
+    /// - Complexity: O(min(*n*, *m*))
+    fn is_disjoint(&self, other: &Self) -> bool;
+
+    /// Returns the changes which transform `from` into `self`: the removed
+    /// and inserted elements, the elements which moved and the elements
+    /// whose value changed, similar to Swift's `difference(from:)`.
+    ///
+    /// Moves are inferred, elements present in both versions that are part of
+    /// a longest sequence keeping their relative order are not reported as
+    /// moved.
+    ///
+    /// - Parameter from: The source version, `self` being the target.
+    /// - Returns: A diff which, applied to `from` using `apply`, produces `self`.
+    /// - Complexity: O(*n* log *n*) where *n* is the total length of both.
+    fn difference_from(&self, from: &Self) -> IdentifiedVecDiff<ID, Element>
+    where
+        Element: PartialEq + Clone;
+
+    /// Applies the changes of `diff`, typically created by `difference_from`,
+    /// to the `identified_vec`.
+    ///
+    /// - Parameter diff: The changes to apply.
+    /// - Returns: `Err` without modifying the `identified_vec` if any removed,
+    ///   moved or updated id is not present, `Error::ExpectedElementNotPresent`,
+    ///   or if any inserted id is already present, `Error::ElementWithSameIDFound`.
+    /// - Complexity: O(*n* log *n*)
+    fn apply(&mut self, diff: IdentifiedVecDiff<ID, Element>) -> Result<(), Error<ID>>;
+
+    /// Three-way merges `self`, "ours", and `theirs`, both edited from their
+    /// common ancestor `base`, combining the insertions, removals, reorders
+    /// and element updates of both sides.
+    ///
+    /// An element changed by only one side takes that change. An element
+    /// changed differently by both sides, or changed by one side and removed
+    /// by the other, is a conflict which `resolve` settles, `ChooseFirst`
+    /// picking ours and `ChooseLast` theirs, where choosing a side that
+    /// removed the element removes it.
+    ///
+    /// The merged order is that of the side which reordered the elements of
+    /// `base`, ours if both or neither did, with the elements only present in
+    /// the other side placed after the same preceding element as in that side.
+    ///
+    /// - Parameter base: The common ancestor of `self` and `theirs`.
+    /// - Parameter theirs: The other version to merge with.
+    /// - Parameter resolve: Closure settling each conflict.
+    /// - Returns: The merged `identified_vec`, using the function identifying
+    ///   elements and hash builder of `self`, and all the conflicts, in the
+    ///   order they were resolved.
+    /// - Complexity: O(*n*) where *n* is the total length of all three.
+    fn merge_three_way<R>(
+        &self,
+        base: &Self,
+        theirs: &Self,
+        resolve: R,
+    ) -> (Self, Vec<MergeConflict<ID, Element>>)
+    where
+        Element: PartialEq + Clone,
+        Self::IdOfElement: Clone,
+        Self::Hasher: Clone,
+        R: FnMut(&MergeConflict<ID, Element>) -> ConflictResolutionChoice;
 }
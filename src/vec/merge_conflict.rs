@@ -0,0 +1,20 @@
+/// A conflict found during a three-way merge, created by
+/// `IsIdentifiedVec::merge_three_way`: the element identified by `id` was
+/// changed differently by both sides, or changed by one side and removed by
+/// the other.
+///
+/// An element which is `None` is not present in that version.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MergeConflict<ID, E> {
+    /// The id of the conflicting element.
+    pub id: ID,
+
+    /// The element in the common ancestor, `None` if both sides inserted it.
+    pub base: Option<E>,
+
+    /// The element in our version, `None` if we removed it.
+    pub ours: Option<E>,
+
+    /// The element in their version, `None` if they removed it.
+    pub theirs: Option<E>,
+}
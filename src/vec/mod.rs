@@ -1,7 +1,21 @@
+mod conflict;
 mod conflict_resolution_choice;
+mod element_mut;
+mod entry;
 mod identified_vec;
+mod identified_vec_diff;
+#[cfg(feature = "serde")]
+mod identified_vec_seed;
 mod is_identified_vec;
+mod merge_conflict;
 
+pub use conflict::*;
 pub use conflict_resolution_choice::*;
+pub use element_mut::*;
+pub use entry::*;
 pub use identified_vec::*;
+pub use identified_vec_diff::{IdentifiedVecChange, IdentifiedVecDiff};
+#[cfg(feature = "serde")]
+pub use identified_vec_seed::*;
 pub use is_identified_vec::*;
+pub use merge_conflict::*;
@@ -1,16 +1,123 @@
+use alloc::string::{String, ToString};
+use core::fmt::{self, Debug, Display};
+
 #[cfg(feature = "serde")]
-#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IdentifiedVecOfSerdeFailure {
-    #[error("Duplicate element at offset {0}")]
     DuplicateElementsAtIndex(usize),
+    KeyMismatchAtIndex(usize),
+    MissingEntityAtIndex(usize),
+    UnreferencedEntityAtIndex(usize),
+}
+
+#[cfg(feature = "serde")]
+impl Display for IdentifiedVecOfSerdeFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateElementsAtIndex(index) => {
+                write!(f, "Duplicate element at offset {index}")
+            }
+            Self::KeyMismatchAtIndex(index) => {
+                write!(f, "Key of element at offset {index} does not match its id")
+            }
+            Self::MissingEntityAtIndex(index) => {
+                write!(f, "No entity found for id at offset {index}")
+            }
+            Self::UnreferencedEntityAtIndex(index) => {
+                write!(f, "Entity at offset {index} is not referenced by any id")
+            }
+        }
+    }
 }
 
-#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
-pub enum Error {
-    #[error("Element with that id: `{0}` not found in collection")]
-    ExpectedElementNotPresent(String),
-    #[error("Duplicate element with same value: `{0}` found")]
-    ElementWithSameValueFound(String),
-    #[error("Duplicate element with same ID: `{0}` found")]
-    ElementWithSameIDFound(String),
+#[cfg(all(feature = "serde", feature = "std"))]
+impl std::error::Error for IdentifiedVecOfSerdeFailure {}
+
+/// The error of the fallible operations of an `identified_vec`, carrying the
+/// offending `ID`.
+///
+/// Convert it into an `ErasedError` if you only need its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error<ID> {
+    ExpectedElementNotPresent {
+        id: ID,
+    },
+    ElementWithSameValueFound {
+        id: ID,
+        index: usize,
+    },
+    ElementWithSameIDFound {
+        id: ID,
+        index: usize,
+    },
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+    IdMismatch {
+        expected: ID,
+        found: ID,
+        index: usize,
+    },
+}
+
+impl<ID> Display for Error<ID>
+where
+    ID: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedElementNotPresent { id } => {
+                write!(f, "Element with that id: `{id:?}` not found in collection")
+            }
+            Self::ElementWithSameValueFound { id, index } => write!(
+                f,
+                "Duplicate element with same value, id: `{id:?}`, found at index {index}"
+            ),
+            Self::ElementWithSameIDFound { id, index } => write!(
+                f,
+                "Duplicate element with same ID: `{id:?}` found at index {index}"
+            ),
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "Index {index} out of bounds for length {len}")
+            }
+            Self::IdMismatch {
+                expected,
+                found,
+                index,
+            } => write!(
+                f,
+                "Element with id `{found:?}` cannot replace element with id `{expected:?}` at index {index}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<ID> std::error::Error for Error<ID> where ID: Debug {}
+
+/// An `Error` with its `ID` type erased, keeping only its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErasedError {
+    pub message: String,
+}
+
+impl Display for ErasedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErasedError {}
+
+impl<ID> From<Error<ID>> for ErasedError
+where
+    ID: Debug,
+{
+    fn from(error: Error<ID>) -> Self {
+        Self {
+            message: error.to_string(),
+        }
+    }
 }
@@ -0,0 +1,72 @@
+use core::fmt::Debug;
+use core::hash::Hash;
+
+use crate::Identifiable;
+
+/// A type level strategy for identifying elements, typically implemented by
+/// a zero-sized marker type, allowing an `IdentifiedVecBy` to be deserialized
+/// or created without passing an `id_of_element` function.
+///
+/// Contrary to `Identifiable`, which an element type can only implement once,
+/// many strategies can identify the same element type in different ways.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{IdStrategy, IdentifiedVecBy, IsIdentifiedVec};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Contact {
+///     email: &'static str,
+///     phone: &'static str,
+/// }
+///
+/// struct ByEmail;
+/// impl IdStrategy<Contact> for ByEmail {
+///     type ID = &'static str;
+///     fn id(element: &Contact) -> Self::ID {
+///         element.email
+///     }
+/// }
+///
+/// struct ByPhone;
+/// impl IdStrategy<Contact> for ByPhone {
+///     type ID = &'static str;
+///     fn id(element: &Contact) -> Self::ID {
+///         element.phone
+///     }
+/// }
+///
+/// let contacts = [
+///     Contact { email: "blob@example.com", phone: "555-1234" },
+///     Contact { email: "blob.jr@example.com", phone: "555-1234" },
+/// ];
+/// let by_email = IdentifiedVecBy::<ByEmail, Contact>::from_iter(contacts.clone());
+/// let by_phone = IdentifiedVecBy::<ByPhone, Contact>::from_iter(contacts);
+/// assert_eq!(by_email.len(), 2);
+/// assert_eq!(by_phone.len(), 1);
+/// ```
+pub trait IdStrategy<Element> {
+    /// The type of the ids of the elements.
+    type ID: Eq + Hash + Clone + Debug;
+
+    /// Returns the id of `element` according to this strategy.
+    fn id(element: &Element) -> Self::ID;
+}
+
+/// The default `IdStrategy`, identifying elements using their `Identifiable`
+/// implementation, making `IdentifiedVecBy<ByIdentifiable, E>`, aliased as
+/// `IdentifiedVecById<E>`, equivalent to `IdentifiedVecOf<E>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ByIdentifiable;
+
+impl<Element> IdStrategy<Element> for ByIdentifiable
+where
+    Element: Identifiable,
+{
+    type ID = Element::ID;
+
+    #[inline]
+    fn id(element: &Element) -> Self::ID {
+        element.id()
+    }
+}
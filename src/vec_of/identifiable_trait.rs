@@ -1,5 +1,5 @@
-use std::fmt::Debug;
-use std::hash::Hash;
+use core::fmt::Debug;
+use core::hash::Hash;
 
 /// The `Identifiable` trait allows you to use the
 /// `IdentifiedVecOf<User> instead of the more verbose
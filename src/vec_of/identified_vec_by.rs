@@ -0,0 +1,789 @@
+use crate::collections::RandomState;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
+use core::hash::BuildHasher;
+use core::marker::PhantomData;
+use core::ops::{Deref, RangeBounds};
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeSeed, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::identified_vec_extract_if::IdentifiedVecExtractIf;
+use crate::identified_vec_into_iterator::IdentifiedVecIntoIterator;
+use crate::identified_vec_iter_mut::IdentifiedVecIterMut;
+use crate::identified_vec_iterator::IdentifiedVecIterator;
+#[cfg(feature = "serde")]
+use crate::IdentifiedVecSeed;
+use crate::{
+    ByIdentifiable, Conflict, ConflictResolutionChoice, ElementMut, Entry, Error, IdStrategy,
+    Identifiable, IdentifiedVec, IdentifiedVecDiff, IdentifiedVecOf, IsIdentifiedVec,
+    MergeConflict,
+};
+
+/// An `IdentifiedVec` identifying its elements using the `IdStrategy` `Z`,
+/// which being part of the type allows it to be created with `new`,
+/// `Default` or `FromIterator` and deserialized, e.g. as a field of a struct
+/// deriving `Deserialize`, without an `id_of_element` function.
+///
+/// Use `ByIdentifiable` as strategy to identify elements using their
+/// `Identifiable` implementation, spelled `IdentifiedVecById<E>`. The strategy
+/// has no default since it precedes the element type, so the alias is the
+/// way to leave it out.
+///
+/// The strategy is zero-sized, but the underlying `IdentifiedVec` still
+/// stores `Z::id` as a function pointer. Implements `IsIdentifiedVec` by
+/// forwarding to the underlying `IdentifiedVec`, except for its constructors,
+/// which ignore the `id_of_element` they are given and use `Z::id`, so the id
+/// function can never be replaced.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{IdStrategy, IdentifiedVecBy, IsIdentifiedVec};
+///
+/// struct ByLowercase;
+/// impl IdStrategy<String> for ByLowercase {
+///     type ID = String;
+///     fn id(element: &String) -> Self::ID {
+///         element.to_lowercase()
+///     }
+/// }
+///
+/// let mut names = IdentifiedVecBy::<ByLowercase, String>::new();
+/// names.append("Blob".to_string());
+/// assert_eq!(names.append("BLOB".to_string()), (false, 0));
+/// assert_eq!(names.ids(), ["blob"]);
+/// ```
+pub struct IdentifiedVecBy<Z, E, H = RandomState>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    identified_vec: Inner<Z, E, H>,
+    _strategy: PhantomData<fn() -> Z>,
+}
+
+/// An `IdentifiedVecBy` identifying its elements using their `Identifiable`
+/// implementation, through the `ByIdentifiable` strategy.
+///
+/// ```
+/// extern crate identified_vec;
+/// use identified_vec::{IdentifiedVecById, IsIdentifiedVec};
+///
+/// let numbers = IdentifiedVecById::<u32>::from_iter([1, 2, 1]);
+/// assert_eq!(numbers.ids(), [1, 2]);
+/// ```
+pub type IdentifiedVecById<E, H = RandomState> = IdentifiedVecBy<ByIdentifiable, E, H>;
+
+/// The `IdentifiedVec` wrapped by an `IdentifiedVecBy<Z, E, H>`.
+type Inner<Z, E, H> = IdentifiedVec<<Z as IdStrategy<E>>::ID, E, IdOf<Z, E>, H>;
+
+/// The id function of the `IdentifiedVec` wrapped by an `IdentifiedVecBy<Z, E, H>`.
+type IdOf<Z, E> = fn(&E) -> <Z as IdStrategy<E>>::ID;
+
+impl<Z, E, H> IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    /// Constructs a new, empty `IdentifiedVecBy`, identifying elements using `Z::id`.
+    #[inline]
+    pub fn new() -> Self
+    where
+        H: Default,
+    {
+        Self::from_identified_vec(IdentifiedVec::new_identifying_element(Z::id))
+    }
+
+    /// Wraps an `identified_vec` which MUST identify its elements using `Z::id`.
+    #[inline]
+    fn from_identified_vec(identified_vec: Inner<Z, E, H>) -> Self {
+        Self {
+            identified_vec,
+            _strategy: PhantomData,
+        }
+    }
+
+    /// Unwraps the underlying `IdentifiedVec`.
+    #[inline]
+    pub fn into_inner(self) -> Inner<Z, E, H> {
+        self.identified_vec
+    }
+}
+
+impl<Z, E, H> IsIdentifiedVec<E, Z::ID> for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    type IdOfElement = IdOf<Z, E>;
+    type Hasher = H;
+
+    /// Constructs a new, empty `IdentifiedVecBy`, ignoring `id_of_element`
+    /// since elements are always identified using `Z::id`.
+    #[inline]
+    fn new_identifying_element(_id_of_element: Self::IdOfElement) -> Self
+    where
+        H: Default,
+    {
+        Self::new()
+    }
+
+    /// See `IsIdentifiedVec::try_from_iter_select_unique_ids_with`, ignoring
+    /// `id_of_element` since elements are always identified using `Z::id`.
+    #[inline]
+    fn try_from_iter_select_unique_ids_with<Er, It, C>(
+        elements: It,
+        _id_of_element: Self::IdOfElement,
+        combine: C,
+    ) -> Result<Self, Er>
+    where
+        C: FnMut(Conflict<&Z::ID, &E>) -> Result<ConflictResolutionChoice, Er>,
+        It: IntoIterator<Item = E>,
+        H: Default,
+    {
+        Inner::<Z, E, H>::try_from_iter_select_unique_ids_with(elements, Z::id, combine)
+            .map(Self::from_identified_vec)
+    }
+
+    /// See `IsIdentifiedVec::from_iter_select_unique_ids_with`, ignoring
+    /// `id_of_element` since elements are always identified using `Z::id`.
+    #[inline]
+    fn from_iter_select_unique_ids_with<It, C>(
+        elements: It,
+        _id_of_element: Self::IdOfElement,
+        combine: C,
+    ) -> Self
+    where
+        C: FnMut(Conflict<&Z::ID, &E>) -> ConflictResolutionChoice,
+        It: IntoIterator<Item = E>,
+        H: Default,
+    {
+        Self::from_identified_vec(Inner::<Z, E, H>::from_iter_select_unique_ids_with(
+            elements,
+            Z::id,
+            combine,
+        ))
+    }
+
+    /// See `IsIdentifiedVec::try_from_iter_merge_unique_ids_with`, ignoring
+    /// `id_of_element` since elements are always identified using `Z::id`.
+    #[inline]
+    fn try_from_iter_merge_unique_ids_with<Er, It, M>(
+        elements: It,
+        _id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Result<Self, Er>
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(Conflict<&Z::ID, E>) -> Result<E, Er>,
+        H: Default,
+    {
+        Inner::<Z, E, H>::try_from_iter_merge_unique_ids_with(elements, Z::id, merge)
+            .map(Self::from_identified_vec)
+    }
+
+    /// See `IsIdentifiedVec::from_iter_merge_unique_ids_with`, ignoring
+    /// `id_of_element` since elements are always identified using `Z::id`.
+    #[inline]
+    fn from_iter_merge_unique_ids_with<It, M>(
+        elements: It,
+        _id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Self
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(Conflict<&Z::ID, E>) -> E,
+        H: Default,
+    {
+        Self::from_identified_vec(Inner::<Z, E, H>::from_iter_merge_unique_ids_with(
+            elements,
+            Z::id,
+            merge,
+        ))
+    }
+
+    #[inline]
+    fn ids(&self) -> Vec<Z::ID> {
+        self.identified_vec.ids()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.identified_vec.len()
+    }
+
+    #[inline]
+    fn index_of_id(&self, id: &Z::ID) -> Option<usize> {
+        self.identified_vec.index_of_id(id)
+    }
+
+    #[inline]
+    fn elements(&self) -> Vec<&E> {
+        self.identified_vec.elements()
+    }
+
+    #[inline]
+    fn contains(&self, element: &E) -> bool {
+        self.identified_vec.contains(element)
+    }
+
+    #[inline]
+    fn contains_id(&self, id: &Z::ID) -> bool {
+        self.identified_vec.contains_id(id)
+    }
+
+    #[inline]
+    fn get(&self, id: &Z::ID) -> Option<&E> {
+        self.identified_vec.get(id)
+    }
+
+    #[inline]
+    fn get_at_index(&self, index: usize) -> Option<&E> {
+        self.identified_vec.get_at_index(index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, id: &Z::ID) -> Option<ElementMut<'_, Z::ID, E>> {
+        self.identified_vec.get_mut(id)
+    }
+
+    #[inline]
+    fn get_at_index_mut(&mut self, index: usize) -> Option<ElementMut<'_, Z::ID, E>> {
+        self.identified_vec.get_at_index_mut(index)
+    }
+
+    #[inline]
+    fn entry(&mut self, id: Z::ID) -> Entry<'_, Z::ID, E, Self::IdOfElement, H> {
+        self.identified_vec.entry(id)
+    }
+
+    #[inline]
+    fn append(&mut self, element: E) -> (bool, usize) {
+        self.identified_vec.append(element)
+    }
+
+    #[inline]
+    fn append_other<It>(&mut self, other: It)
+    where
+        It: IntoIterator<Item = E>,
+    {
+        self.identified_vec.append_other(other)
+    }
+
+    #[inline]
+    fn append_other_merging<It, M>(&mut self, other: It, merge: M)
+    where
+        It: IntoIterator<Item = E>,
+        M: FnMut(&mut E, E),
+    {
+        self.identified_vec.append_other_merging(other, merge)
+    }
+
+    #[inline]
+    fn update_or_append(&mut self, element: E) -> Option<E> {
+        self.identified_vec.update_or_append(element)
+    }
+
+    #[inline]
+    fn update_at(&mut self, element: E, index: usize) -> E {
+        self.identified_vec.update_at(element, index)
+    }
+
+    #[inline]
+    fn try_update_at(&mut self, element: E, index: usize) -> Result<E, Error<Z::ID>> {
+        self.identified_vec.try_update_at(element, index)
+    }
+
+    #[inline]
+    fn update_with<F>(&mut self, id: &Z::ID, mutate: F) -> bool
+    where
+        F: FnMut(&mut E),
+    {
+        self.identified_vec.update_with(id, mutate)
+    }
+
+    #[inline]
+    fn try_update_with<F, Er>(&mut self, id: &Z::ID, mutate: F) -> Result<bool, Er>
+    where
+        F: FnMut(&mut E) -> Result<E, Er>,
+    {
+        self.identified_vec.try_update_with(id, mutate)
+    }
+
+    #[inline]
+    fn insert(&mut self, element: E, at: usize) -> (bool, usize) {
+        self.identified_vec.insert(element, at)
+    }
+
+    #[inline]
+    fn try_insert(&mut self, element: E, at: usize) -> Result<(bool, usize), Error<Z::ID>> {
+        self.identified_vec.try_insert(element, at)
+    }
+
+    #[inline]
+    fn update_or_insert(&mut self, element: E, index: usize) -> (Option<E>, usize) {
+        self.identified_vec.update_or_insert(element, index)
+    }
+
+    #[inline]
+    fn try_update(&mut self, element: E) -> Result<E, Error<Z::ID>> {
+        self.identified_vec.try_update(element)
+    }
+
+    #[inline]
+    fn remove_by_id(&mut self, id: &Z::ID) -> Option<E> {
+        self.identified_vec.remove_by_id(id)
+    }
+
+    #[inline]
+    fn remove(&mut self, element: &E) -> Option<E> {
+        self.identified_vec.remove(element)
+    }
+
+    #[inline]
+    fn remove_at(&mut self, index: usize) -> E {
+        self.identified_vec.remove_at(index)
+    }
+
+    #[inline]
+    fn try_remove_at(&mut self, index: usize) -> Result<E, Error<Z::ID>> {
+        self.identified_vec.try_remove_at(index)
+    }
+
+    #[inline]
+    fn remove_at_offsets<It>(&mut self, offsets: It)
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        self.identified_vec.remove_at_offsets(offsets)
+    }
+
+    #[inline]
+    fn try_remove_at_offsets<It>(&mut self, offsets: It) -> Result<(), Error<Z::ID>>
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        self.identified_vec.try_remove_at_offsets(offsets)
+    }
+
+    #[inline]
+    fn try_append_new(&mut self, element: E) -> Result<(bool, usize), Error<Z::ID>> {
+        self.identified_vec.try_append_new(element)
+    }
+
+    #[inline]
+    fn iter(&self) -> IdentifiedVecIterator<'_, Z::ID, E, H> {
+        self.identified_vec.iter()
+    }
+
+    #[inline]
+    fn iter_mut(&mut self) -> IdentifiedVecIterMut<'_, Z::ID, E> {
+        self.identified_vec.iter_mut()
+    }
+
+    #[inline]
+    fn values_mut(&mut self) -> Vec<ElementMut<'_, Z::ID, E>> {
+        self.identified_vec.values_mut()
+    }
+
+    #[inline]
+    fn sort(&mut self)
+    where
+        E: Ord,
+    {
+        self.identified_vec.sort()
+    }
+
+    #[inline]
+    fn sort_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&E, &E) -> core::cmp::Ordering,
+    {
+        self.identified_vec.sort_by(compare)
+    }
+
+    #[inline]
+    fn sort_by_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&E) -> K,
+        K: Ord,
+    {
+        self.identified_vec.sort_by_key(key)
+    }
+
+    #[inline]
+    fn sort_unstable_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&E, &E) -> core::cmp::Ordering,
+    {
+        self.identified_vec.sort_unstable_by(compare)
+    }
+
+    #[inline]
+    fn sort_by_cached_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&E) -> K,
+        K: Ord,
+    {
+        self.identified_vec.sort_by_cached_key(key)
+    }
+
+    #[inline]
+    fn sort_by_id(&mut self)
+    where
+        Z::ID: Ord,
+    {
+        self.identified_vec.sort_by_id()
+    }
+
+    #[inline]
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.identified_vec.move_element(from, to)
+    }
+
+    #[inline]
+    fn move_from_offsets<It>(&mut self, offsets: It, to_offset: usize)
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        self.identified_vec.move_from_offsets(offsets, to_offset)
+    }
+
+    #[inline]
+    fn swap_at(&mut self, i: usize, j: usize) {
+        self.identified_vec.swap_at(i, j)
+    }
+
+    #[inline]
+    fn swap_ids(&mut self, lhs: &Z::ID, rhs: &Z::ID) -> bool {
+        self.identified_vec.swap_ids(lhs, rhs)
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.identified_vec.reverse()
+    }
+
+    #[inline]
+    fn rotate_left(&mut self, mid: usize) {
+        self.identified_vec.rotate_left(mid)
+    }
+
+    #[inline]
+    fn rotate_right(&mut self, k: usize) {
+        self.identified_vec.rotate_right(k)
+    }
+
+    #[inline]
+    fn retain<P>(&mut self, keep: P)
+    where
+        P: FnMut(&E) -> bool,
+    {
+        self.identified_vec.retain(keep)
+    }
+
+    #[inline]
+    fn retain_mut<P>(&mut self, keep: P)
+    where
+        P: FnMut(&mut E) -> bool,
+    {
+        self.identified_vec.retain_mut(keep)
+    }
+
+    #[inline]
+    fn extract_if<P>(
+        &mut self,
+        extract: P,
+    ) -> IdentifiedVecExtractIf<'_, Z::ID, E, Self::IdOfElement, H, P>
+    where
+        P: FnMut(&mut E) -> bool,
+        H: Default,
+    {
+        self.identified_vec.extract_if(extract)
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.identified_vec.clear()
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.identified_vec.truncate(len)
+    }
+
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        H: Clone,
+    {
+        Self::from_identified_vec(self.identified_vec.split_off(at))
+    }
+
+    #[inline]
+    fn drain<R>(&mut self, range: R) -> alloc::vec::IntoIter<E>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.identified_vec.drain(range)
+    }
+
+    #[inline]
+    fn union(self, other: &Self, choice: ConflictResolutionChoice) -> Self
+    where
+        E: Clone,
+    {
+        Self::from_identified_vec(self.identified_vec.union(&other.identified_vec, choice))
+    }
+
+    #[inline]
+    fn union_with<M>(self, other: &Self, merge: M) -> Self
+    where
+        E: Clone,
+        M: FnMut(&mut E, E),
+    {
+        Self::from_identified_vec(self.identified_vec.union_with(&other.identified_vec, merge))
+    }
+
+    #[inline]
+    fn intersection(self, other: &Self) -> Self {
+        Self::from_identified_vec(self.identified_vec.intersection(&other.identified_vec))
+    }
+
+    #[inline]
+    fn difference(self, other: &Self) -> Self {
+        Self::from_identified_vec(self.identified_vec.difference(&other.identified_vec))
+    }
+
+    #[inline]
+    fn symmetric_difference(self, other: &Self) -> Self
+    where
+        E: Clone,
+    {
+        Self::from_identified_vec(
+            self.identified_vec
+                .symmetric_difference(&other.identified_vec),
+        )
+    }
+
+    #[inline]
+    fn is_subset(&self, other: &Self) -> bool {
+        self.identified_vec.is_subset(&other.identified_vec)
+    }
+
+    #[inline]
+    fn is_superset(&self, other: &Self) -> bool {
+        self.identified_vec.is_superset(&other.identified_vec)
+    }
+
+    #[inline]
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.identified_vec.is_disjoint(&other.identified_vec)
+    }
+
+    #[inline]
+    fn difference_from(&self, from: &Self) -> IdentifiedVecDiff<Z::ID, E>
+    where
+        E: PartialEq + Clone,
+    {
+        self.identified_vec.difference_from(&from.identified_vec)
+    }
+
+    #[inline]
+    fn apply(&mut self, diff: IdentifiedVecDiff<Z::ID, E>) -> Result<(), Error<Z::ID>> {
+        self.identified_vec.apply(diff)
+    }
+
+    #[inline]
+    fn merge_three_way<R>(
+        &self,
+        base: &Self,
+        theirs: &Self,
+        resolve: R,
+    ) -> (Self, Vec<MergeConflict<Z::ID, E>>)
+    where
+        E: PartialEq + Clone,
+        H: Clone,
+        R: FnMut(&MergeConflict<Z::ID, E>) -> ConflictResolutionChoice,
+    {
+        let (merged, conflicts) = self.identified_vec.merge_three_way(
+            &base.identified_vec,
+            &theirs.identified_vec,
+            resolve,
+        );
+        (Self::from_identified_vec(merged), conflicts)
+    }
+}
+
+impl<Z, E, H> Default for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Z, E, H> FromIterator<E> for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher + Default,
+{
+    /// Creates an `IdentifiedVecBy` from the elements of `iter`, ignoring any
+    /// element whose id is already present.
+    fn from_iter<It: IntoIterator<Item = E>>(iter: It) -> Self {
+        let mut identified_vec_by = Self::new();
+        identified_vec_by.extend(iter);
+        identified_vec_by
+    }
+}
+
+impl<Z, E, H> Extend<E> for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    /// Appends the elements of `iter`, ignoring any element whose id is
+    /// already present.
+    fn extend<It: IntoIterator<Item = E>>(&mut self, iter: It) {
+        self.identified_vec.append_other(iter)
+    }
+}
+
+impl<Z, E, H> IntoIterator for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    type Item = E;
+    type IntoIter = IdentifiedVecIntoIterator<Z::ID, E, H>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.identified_vec.into_iter()
+    }
+}
+
+impl<'a, Z, E, H> IntoIterator for &'a IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    type Item = &'a E;
+    type IntoIter = IdentifiedVecIterator<'a, Z::ID, E, H>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.identified_vec.iter()
+    }
+}
+
+impl<E, H> From<IdentifiedVecOf<E, H>> for IdentifiedVecBy<ByIdentifiable, E, H>
+where
+    E: Identifiable,
+    H: BuildHasher + Default,
+{
+    /// Re-identifies the elements of `identified_vec_of` using their
+    /// `Identifiable` implementation, since it might have been created with
+    /// any `id_of_element` function, ignoring any element whose id is already
+    /// present.
+    fn from(identified_vec_of: IdentifiedVecOf<E, H>) -> Self {
+        identified_vec_of.into_iter().collect()
+    }
+}
+
+impl<Z, E, H> Deref for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    H: BuildHasher,
+{
+    type Target = Inner<Z, E, H>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.identified_vec
+    }
+}
+
+impl<Z, E, H> Clone for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Clone,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from_identified_vec(self.identified_vec.clone())
+    }
+}
+
+impl<Z, E, H> Debug for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.identified_vec, f)
+    }
+}
+
+impl<Z, E, H> Display for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.identified_vec, f)
+    }
+}
+
+impl<Z, E, H> PartialEq for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: PartialEq,
+    H: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.identified_vec == other.identified_vec
+    }
+}
+
+impl<Z, E, H> Eq for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Eq,
+    H: BuildHasher,
+{
+}
+
+///////////////////////
+////    SERDE       ///
+///////////////////////
+#[cfg(feature = "serde")]
+impl<Z, E, H> Serialize for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Serialize,
+    H: BuildHasher,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        self.identified_vec.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Z, E, H> Deserialize<'de> for IdentifiedVecBy<Z, E, H>
+where
+    Z: IdStrategy<E>,
+    E: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    #[cfg(not(tarpaulin_include))] // false negative
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        IdentifiedVecSeed::new(Z::id as fn(&E) -> Z::ID)
+            .deserialize(deserializer)
+            .map(Self::from_identified_vec)
+    }
+}
@@ -1,21 +1,24 @@
-use std::collections::HashMap;
+use crate::collections::{HashMap, RandomState};
+use alloc::vec::Vec;
 
 #[cfg(feature = "serde")]
-use std::fmt::Debug;
+use core::fmt::Debug;
+use core::hash::BuildHasher;
 
 #[cfg(feature = "serde")]
-use super::errors::IdentifiedVecOfSerdeFailure;
-use crate::{ConflictResolutionChoice, IdentifiedVec, IsIdentifiedVec, IsIdentifiedVecOf};
+use crate::IdentifiedVecSeed;
+use crate::{
+    Conflict, ConflictResolutionChoice, IdentifiedVec, IsIdentifiedVec, IsIdentifiedVecOf,
+};
 
 #[cfg(feature = "serde")]
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::DeserializeSeed, Deserialize, Deserializer};
 
 use super::identifiable_trait::Identifiable;
 
 ///////////////////////
 /// IdentifiedVecOf ///
 ///////////////////////
-
 /// A type alias for `IdentifiedVec<Element::ID, Element>`, this is the
 /// preferred and most powerful collection type of this crate, requires
 /// that your `Element`s impl the `Identifiable` trait. Using this collection
@@ -24,7 +27,18 @@ use super::identifiable_trait::Identifiable;
 /// `IdentifiedVecOf` together with feature "serde" also gives serde
 /// serialization/deserialization as if it were a `Vec<Element>`, given that
 /// `Element` implements serde serialization/deserialization of course.
-pub type IdentifiedVecOf<Element> = IdentifiedVec<<Element as Identifiable>::ID, Element>;
+///
+/// Like `HashMap`, `IdentifiedVecOf` uses `RandomState` to hash ids by default,
+/// you can use another hasher by specifying the `S` type parameter, and like
+/// `HashMap::new`, the constructors of `IsIdentifiedVecOf` are only available
+/// for the default hasher, use `with_hasher` or `new_identifying_element`
+/// with `Identifiable::id` for other hashers.
+pub type IdentifiedVecOf<Element, S = RandomState> = IdentifiedVec<
+    <Element as Identifiable>::ID,
+    Element,
+    fn(&Element) -> <Element as Identifiable>::ID,
+    S,
+>;
 
 impl<Element> IsIdentifiedVecOf<Element> for IdentifiedVecOf<Element>
 where
@@ -35,6 +49,7 @@ where
     fn new() -> Self {
         Self {
             order: Vec::new(),
+            indices: HashMap::new(),
             elements: HashMap::new(),
             _id_of_element: |i| i.id(),
         }
@@ -60,7 +75,7 @@ where
         unique_elements
             .into_iter()
             .for_each(|e| _ = _self.append(e));
-        return _self;
+        _self
     }
 
     /// Creates a new `identified_vec` from the elements in the given sequence, using a combining closure to
@@ -74,16 +89,14 @@ where
     ///
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
-    ///   - combine: Closure trying to combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
+    ///   - combine: Closure trying to combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
     ///   implements high-quality hashing.
     #[inline]
-    fn try_from_iter_select_unique_with<E, I>(
-        elements: I,
-        combine: fn((usize, &Element, &Element)) -> Result<ConflictResolutionChoice, E>,
-    ) -> Result<Self, E>
+    fn try_from_iter_select_unique_with<E, I, C>(elements: I, combine: C) -> Result<Self, E>
     where
+        C: FnMut(Conflict<&Element::ID, &Element>) -> Result<ConflictResolutionChoice, E>,
         I: IntoIterator<Item = Element>,
     {
         Self::try_from_iter_select_unique_ids_with(elements, |e| e.id(), combine)
@@ -100,51 +113,106 @@ where
     ///
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
-    ///   - combine: Closure used combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
+    ///   - combine: Closure used combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
     ///   implements high-quality hashing.
     #[inline]
-    fn from_iter_select_unique_with<I>(
-        elements: I,
-        combine: fn((usize, &Element, &Element)) -> ConflictResolutionChoice,
-    ) -> Self
+    fn from_iter_select_unique_with<I, C>(elements: I, combine: C) -> Self
     where
+        C: FnMut(Conflict<&Element::ID, &Element>) -> ConflictResolutionChoice,
         I: IntoIterator<Item = Element>,
     {
         Self::from_iter_select_unique_ids_with(elements, |e| e.id(), combine)
     }
 }
 
-///////////////////////
-////    SERDE       ///
-///////////////////////
-#[cfg(feature = "serde")]
-impl<Element> Serialize for IdentifiedVecOf<Element>
+impl<Element> IdentifiedVecOf<Element>
 where
-    Element: Serialize + Identifiable + Debug + Clone,
+    Element: Identifiable,
 {
-    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    /// Creates a new `IdentifiedVecOf` from the elements in the given sequence,
+    /// ignoring any element whose id is already present.
+    ///
+    /// Inherent, so that `IdentifiedVecOf::from_iter` is not ambiguous between
+    /// `FromIterator` and `IsIdentifiedVecOf`, which behave the same, use
+    /// `collect` for other hashers.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn from_iter<It>(unique_elements: It) -> Self
     where
-        S: Serializer,
+        It: IntoIterator<Item = Element>,
     {
-        Vec::serialize(&self.elements(), serializer)
+        <Self as FromIterator<Element>>::from_iter(unique_elements)
+    }
+}
+
+impl<Element, H> Default for IdentifiedVecOf<Element, H>
+where
+    Element: Identifiable,
+    H: BuildHasher + Default,
+{
+    /// Constructs a new, empty `IdentifiedVecOf`.
+    #[inline]
+    fn default() -> Self {
+        Self::new_identifying_element(Element::id)
+    }
+}
+
+impl<Element, H> FromIterator<Element> for IdentifiedVecOf<Element, H>
+where
+    Element: Identifiable,
+    H: BuildHasher + Default,
+{
+    /// Creates an `IdentifiedVecOf` from the elements of `iter`, ignoring any
+    /// element whose id is already present, like `IsIdentifiedVecOf::from_iter`.
+    #[inline]
+    fn from_iter<It: IntoIterator<Item = Element>>(iter: It) -> Self {
+        let mut identified_vec = Self::default();
+        identified_vec.extend(iter);
+        identified_vec
+    }
+}
+
+impl<Element, H> From<Vec<Element>> for IdentifiedVecOf<Element, H>
+where
+    Element: Identifiable,
+    H: BuildHasher + Default,
+{
+    /// Creates an `IdentifiedVecOf` from `elements`, ignoring any element
+    /// whose id is already present.
+    #[inline]
+    fn from(elements: Vec<Element>) -> Self {
+        elements.into_iter().collect()
     }
 }
 
+impl<Element, H, const N: usize> From<[Element; N]> for IdentifiedVecOf<Element, H>
+where
+    Element: Identifiable,
+    H: BuildHasher + Default,
+{
+    /// Creates an `IdentifiedVecOf` from `elements`, ignoring any element
+    /// whose id is already present.
+    #[inline]
+    fn from(elements: [Element; N]) -> Self {
+        elements.into_iter().collect()
+    }
+}
+
+///////////////////////
+////    SERDE       ///
+///////////////////////
 #[cfg(feature = "serde")]
-impl<'de, Element> Deserialize<'de> for IdentifiedVecOf<Element>
+impl<'de, Element, H> Deserialize<'de> for IdentifiedVecOf<Element, H>
 where
     Element: Deserialize<'de> + Identifiable + Debug + Clone,
+    H: BuildHasher + Default,
 {
     #[cfg(not(tarpaulin_include))] // false negative
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
-    ) -> Result<IdentifiedVecOf<Element>, D::Error> {
-        let elements = Vec::<Element>::deserialize(deserializer)?;
-        IdentifiedVecOf::<Element>::try_from_iter_select_unique_with(elements, |(idx, _, _)| {
-            Err(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(idx))
-        })
-        .map_err(de::Error::custom)
+    ) -> Result<IdentifiedVecOf<Element, H>, D::Error> {
+        IdentifiedVecSeed::new(Element::id as fn(&Element) -> Element::ID).deserialize(deserializer)
     }
 }
@@ -1,6 +1,8 @@
-use crate::{ConflictResolutionChoice, Identifiable, IsIdentifiedVec};
+use crate::{Conflict, ConflictResolutionChoice, Identifiable, IsIdentifiedVec};
 
-pub trait IsIdentifiedVecOf<Element: Identifiable>: IsIdentifiedVec<Element, Element::ID> {
+pub trait IsIdentifiedVecOf<Element: Identifiable>:
+    IsIdentifiedVec<Element, Element::ID, IdOfElement = fn(&Element) -> Element::ID>
+{
     /// Constructs a new, empty `IdentifiedVec<ID, Element>`, using `id()` on `Element`
     /// as id function.
     fn new() -> Self;
@@ -20,16 +22,15 @@ pub trait IsIdentifiedVecOf<Element: Identifiable>: IsIdentifiedVec<Element, Ele
     ///
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
-    ///   - combine: Closure trying to combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
+    ///   - combine: Closure trying to combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`), or `Err` if you prefer.
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
     ///   implements high-quality hashing.
-    fn try_from_iter_select_unique_with<E, I>(
-        elements: I,
-        combine: fn((usize, &Element, &Element)) -> Result<ConflictResolutionChoice, E>,
-    ) -> Result<Self, E>
+    fn try_from_iter_select_unique_with<E, I, C>(elements: I, combine: C) -> Result<Self, E>
     where
+        C: FnMut(Conflict<&Element::ID, &Element>) -> Result<ConflictResolutionChoice, E>,
         I: IntoIterator<Item = Element>,
+        Self::Hasher: Default,
     {
         Self::try_from_iter_select_unique_ids_with(elements, |e| e.id(), combine)
     }
@@ -45,17 +46,55 @@ pub trait IsIdentifiedVecOf<Element: Identifiable>: IsIdentifiedVec<Element, Ele
     ///
     /// - Parameters:
     ///   - elements: A sequence of elements to use for the new `identified_vec`.
-    ///   - combine: Closure used combine elements `(index, first, last)` with duplicate ids, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
+    ///   - combine: Closure used combine elements with duplicate ids, given the `Conflict`, returning which element to use, by use of ConflictResolutionChoice (`ChooseFirst` or `ChooseLast`)
     /// - Returns: A new `identified_vec` initialized with the unique elements of `elements`.
     /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
     ///   implements high-quality hashing.
-    fn from_iter_select_unique_with<I>(
-        elements: I,
-        combine: fn((usize, &Element, &Element)) -> ConflictResolutionChoice,
-    ) -> Self
+    fn from_iter_select_unique_with<I, C>(elements: I, combine: C) -> Self
     where
+        C: FnMut(Conflict<&Element::ID, &Element>) -> ConflictResolutionChoice,
         I: IntoIterator<Item = Element>,
+        Self::Hasher: Default,
     {
         Self::from_iter_select_unique_ids_with(elements, |e| e.id(), combine)
     }
+
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate ids into a new element.
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - merge: Closure trying to merge elements with duplicate ids, given the `Conflict` into a new element, which must have the same id, or `Err` if you prefer.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`,
+    ///   or the first `Err` of `merge`.
+    /// - Warning: `merge` takes ownership of both elements of the `Conflict`, so if it returns `Err`, or
+    ///   panics, they are dropped, together with every element consumed from `elements` so far.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
+    ///   implements high-quality hashing.
+    fn try_from_iter_merge_unique_with<E, I, M>(elements: I, merge: M) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&Element::ID, Element>) -> Result<Element, E>,
+        Self::Hasher: Default,
+    {
+        Self::try_from_iter_merge_unique_ids_with(elements, |e| e.id(), merge)
+    }
+
+    /// Creates a new `identified_vec` from the elements in the given sequence, using a merging closure to
+    /// combine any elements with duplicate ids into a new element.
+    ///
+    /// - Parameters:
+    ///   - elements: A sequence of elements to use for the new `identified_vec`.
+    ///   - merge: Closure merging elements with duplicate ids, given the `Conflict` into a new element, which must have the same id.
+    /// - Returns: A new `identified_vec` initialized with the unique, possibly merged, elements of `elements`.
+    /// - Complexity: Expected O(*n*) on average, where *n* is the count of elements, if `ID`
+    ///   implements high-quality hashing.
+    fn from_iter_merge_unique_with<I, M>(elements: I, merge: M) -> Self
+    where
+        I: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&Element::ID, Element>) -> Element,
+        Self::Hasher: Default,
+    {
+        Self::from_iter_merge_unique_ids_with(elements, |e| e.id(), merge)
+    }
 }
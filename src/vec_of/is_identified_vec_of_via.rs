@@ -1,21 +1,29 @@
 use crate::{
-    identified_vec_iterator::IdentifiedVecIterator, ConflictResolutionChoice, Error,
-    IsIdentifiedVec, IsIdentifiedVecOf, ItemsCloned,
+    identified_vec_extract_if::IdentifiedVecExtractIf,
+    identified_vec_iter_mut::IdentifiedVecIterMut, identified_vec_iterator::IdentifiedVecIterator,
+    Conflict, ConflictResolutionChoice, ElementMut, Entry, Error, IdentifiedVecDiff,
+    IsIdentifiedVec, IsIdentifiedVecOf, ItemsCloned, MergeConflict,
 };
+use alloc::vec::Vec;
 
 use super::{identifiable_trait::Identifiable, identified_vec_of::IdentifiedVecOf};
+use core::hash::BuildHasher;
+use core::ops::RangeBounds;
 
 /// https://stackoverflow.com/a/66537661/1311272
 pub trait ViaMarker {}
 
-pub trait IsIdentifiableVecOfVia<Element>:
-    IsIdentifiedVecOf<Element> + IntoIterator<Item = Element> + ViaMarker
+pub trait IsIdentifiableVecOfVia<Element>: IntoIterator<Item = Element> + ViaMarker
 where
     Element: Identifiable,
 {
-    fn from_identified_vec_of(identified_vec_of: IdentifiedVecOf<Element>) -> Self;
-    fn via_mut(&mut self) -> &mut IdentifiedVecOf<Element>;
-    fn via(&self) -> &IdentifiedVecOf<Element>;
+    /// The hash builder used by the wrapped `IdentifiedVecOf`.
+    type Hasher: BuildHasher + Default;
+
+    fn from_identified_vec_of(identified_vec_of: IdentifiedVecOf<Element, Self::Hasher>) -> Self;
+    fn via_mut(&mut self) -> &mut IdentifiedVecOf<Element, Self::Hasher>;
+    fn via(&self) -> &IdentifiedVecOf<Element, Self::Hasher>;
+    fn into_via(self) -> IdentifiedVecOf<Element, Self::Hasher>;
 }
 
 impl<Element, U> IsIdentifiedVecOf<Element> for U
@@ -26,7 +34,7 @@ where
 {
     #[inline]
     fn new() -> Self {
-        Self::from_identified_vec_of(IdentifiedVecOf::new())
+        Self::from_identified_vec_of(IdentifiedVecOf::new_identifying_element(|e| e.id()))
     }
 
     #[inline]
@@ -34,7 +42,9 @@ where
     where
         It: IntoIterator<Item = Element>,
     {
-        Self::from_identified_vec_of(IdentifiedVecOf::from_iter(unique_elements))
+        let mut identified_vec_of = Self::new();
+        identified_vec_of.append_other(unique_elements);
+        identified_vec_of
     }
 }
 
@@ -55,20 +65,24 @@ where
     Element: Identifiable,
     U: IsIdentifiableVecOfVia<Element>,
 {
+    type IdOfElement = fn(&Element) -> <Element as Identifiable>::ID;
+    type Hasher = <U as IsIdentifiableVecOfVia<Element>>::Hasher;
+
     #[inline]
-    fn new_identifying_element(
-        id_of_element: fn(&Element) -> <Element as Identifiable>::ID,
-    ) -> Self {
+    fn new_identifying_element(id_of_element: Self::IdOfElement) -> Self {
         Self::from_identified_vec_of(IdentifiedVecOf::new_identifying_element(id_of_element))
     }
 
     #[inline]
-    fn try_from_iter_select_unique_ids_with<Er, It>(
+    fn try_from_iter_select_unique_ids_with<Er, It, C>(
         elements: It,
-        id_of_element: fn(&Element) -> <Element as Identifiable>::ID,
-        combine: fn((usize, &Element, &Element)) -> Result<ConflictResolutionChoice, Er>,
+        id_of_element: Self::IdOfElement,
+        combine: C,
     ) -> Result<Self, Er>
     where
+        C: FnMut(
+            Conflict<&<Element as Identifiable>::ID, &Element>,
+        ) -> Result<ConflictResolutionChoice, Er>,
         It: IntoIterator<Item = Element>,
     {
         IdentifiedVecOf::try_from_iter_select_unique_ids_with(elements, id_of_element, combine)
@@ -76,12 +90,13 @@ where
     }
 
     #[inline]
-    fn from_iter_select_unique_ids_with<It>(
+    fn from_iter_select_unique_ids_with<It, C>(
         elements: It,
-        id_of_element: fn(&Element) -> <Element as Identifiable>::ID,
-        combine: fn((usize, &Element, &Element)) -> ConflictResolutionChoice,
+        id_of_element: Self::IdOfElement,
+        combine: C,
     ) -> Self
     where
+        C: FnMut(Conflict<&<Element as Identifiable>::ID, &Element>) -> ConflictResolutionChoice,
         It: IntoIterator<Item = Element>,
     {
         Self::from_identified_vec_of(IdentifiedVecOf::from_iter_select_unique_ids_with(
@@ -91,6 +106,37 @@ where
         ))
     }
 
+    #[inline]
+    fn try_from_iter_merge_unique_ids_with<Er, It, M>(
+        elements: It,
+        id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Result<Self, Er>
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&<Element as Identifiable>::ID, Element>) -> Result<Element, Er>,
+    {
+        IdentifiedVecOf::try_from_iter_merge_unique_ids_with(elements, id_of_element, merge)
+            .map(|via| Self::from_identified_vec_of(via))
+    }
+
+    #[inline]
+    fn from_iter_merge_unique_ids_with<It, M>(
+        elements: It,
+        id_of_element: Self::IdOfElement,
+        merge: M,
+    ) -> Self
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(Conflict<&<Element as Identifiable>::ID, Element>) -> Element,
+    {
+        Self::from_identified_vec_of(IdentifiedVecOf::from_iter_merge_unique_ids_with(
+            elements,
+            id_of_element,
+            merge,
+        ))
+    }
+
     #[inline]
     fn ids(&self) -> Vec<<Element as Identifiable>::ID> {
         self.via().ids()
@@ -131,6 +177,30 @@ where
         self.via().get_at_index(index)
     }
 
+    #[inline]
+    fn get_mut(
+        &mut self,
+        id: &<Element as Identifiable>::ID,
+    ) -> Option<ElementMut<'_, <Element as Identifiable>::ID, Element>> {
+        self.via_mut().get_mut(id)
+    }
+
+    #[inline]
+    fn get_at_index_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<ElementMut<'_, <Element as Identifiable>::ID, Element>> {
+        self.via_mut().get_at_index_mut(index)
+    }
+
+    #[inline]
+    fn entry(
+        &mut self,
+        id: Element::ID,
+    ) -> Entry<'_, Element::ID, Element, Self::IdOfElement, Self::Hasher> {
+        self.via_mut().entry(id)
+    }
+
     #[inline]
     fn append(&mut self, element: Element) -> (bool, usize) {
         self.via_mut().append(element)
@@ -154,18 +224,36 @@ where
         self.via_mut().update_at(element, index)
     }
 
+    #[inline]
+    fn try_update_at(
+        &mut self,
+        element: Element,
+        index: usize,
+    ) -> Result<Element, Error<Element::ID>> {
+        self.via_mut().try_update_at(element, index)
+    }
+
     #[inline]
     fn insert(&mut self, element: Element, at: usize) -> (bool, usize) {
         self.via_mut().insert(element, at)
     }
 
+    #[inline]
+    fn try_insert(
+        &mut self,
+        element: Element,
+        at: usize,
+    ) -> Result<(bool, usize), Error<Element::ID>> {
+        self.via_mut().try_insert(element, at)
+    }
+
     #[inline]
     fn update_or_insert(&mut self, element: Element, index: usize) -> (Option<Element>, usize) {
         self.via_mut().update_or_insert(element, index)
     }
 
     #[inline]
-    fn try_update(&mut self, element: Element) -> Result<Element, Error> {
+    fn try_update(&mut self, element: Element) -> Result<Element, Error<Element::ID>> {
         self.via_mut().try_update(element)
     }
 
@@ -192,7 +280,7 @@ where
     }
 
     #[inline]
-    fn try_append_new(&mut self, element: Element) -> Result<(bool, usize), Error> {
+    fn try_append_new(&mut self, element: Element) -> Result<(bool, usize), Error<Element::ID>> {
         self.via_mut().try_append_new(element)
     }
 
@@ -214,6 +302,11 @@ where
         self.via_mut().remove_at(index)
     }
 
+    #[inline]
+    fn try_remove_at(&mut self, index: usize) -> Result<Element, Error<Element::ID>> {
+        self.via_mut().try_remove_at(index)
+    }
+
     #[inline]
     fn remove_at_offsets<It>(&mut self, offsets: It)
     where
@@ -223,7 +316,282 @@ where
     }
 
     #[inline]
-    fn iter(&self) -> IdentifiedVecIterator<<Element as Identifiable>::ID, Element> {
+    fn try_remove_at_offsets<It>(&mut self, offsets: It) -> Result<(), Error<Element::ID>>
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        self.via_mut().try_remove_at_offsets(offsets)
+    }
+
+    #[inline]
+    fn iter(
+        &self,
+    ) -> IdentifiedVecIterator<'_, <Element as Identifiable>::ID, Element, Self::Hasher> {
         self.via().iter()
     }
+
+    #[inline]
+    fn iter_mut(&mut self) -> IdentifiedVecIterMut<'_, <Element as Identifiable>::ID, Element> {
+        self.via_mut().iter_mut()
+    }
+
+    #[inline]
+    fn values_mut(&mut self) -> Vec<ElementMut<'_, <Element as Identifiable>::ID, Element>> {
+        self.via_mut().values_mut()
+    }
+
+    #[inline]
+    fn sort(&mut self)
+    where
+        Element: Ord,
+    {
+        self.via_mut().sort()
+    }
+
+    #[inline]
+    fn sort_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&Element, &Element) -> core::cmp::Ordering,
+    {
+        self.via_mut().sort_by(compare)
+    }
+
+    #[inline]
+    fn sort_by_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&Element) -> K,
+        K: Ord,
+    {
+        self.via_mut().sort_by_key(key)
+    }
+
+    #[inline]
+    fn sort_unstable_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&Element, &Element) -> core::cmp::Ordering,
+    {
+        self.via_mut().sort_unstable_by(compare)
+    }
+
+    #[inline]
+    fn sort_by_cached_key<K, C>(&mut self, key: C)
+    where
+        C: FnMut(&Element) -> K,
+        K: Ord,
+    {
+        self.via_mut().sort_by_cached_key(key)
+    }
+
+    #[inline]
+    fn sort_by_id(&mut self)
+    where
+        <Element as Identifiable>::ID: Ord,
+    {
+        self.via_mut().sort_by_id()
+    }
+
+    #[inline]
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.via_mut().move_element(from, to)
+    }
+
+    #[inline]
+    fn move_from_offsets<It>(&mut self, offsets: It, to_offset: usize)
+    where
+        It: IntoIterator<Item = usize>,
+    {
+        self.via_mut().move_from_offsets(offsets, to_offset)
+    }
+
+    #[inline]
+    fn swap_at(&mut self, i: usize, j: usize) {
+        self.via_mut().swap_at(i, j)
+    }
+
+    #[inline]
+    fn swap_ids(
+        &mut self,
+        lhs: &<Element as Identifiable>::ID,
+        rhs: &<Element as Identifiable>::ID,
+    ) -> bool {
+        self.via_mut().swap_ids(lhs, rhs)
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.via_mut().reverse()
+    }
+
+    #[inline]
+    fn rotate_left(&mut self, mid: usize) {
+        self.via_mut().rotate_left(mid)
+    }
+
+    #[inline]
+    fn rotate_right(&mut self, k: usize) {
+        self.via_mut().rotate_right(k)
+    }
+
+    #[inline]
+    fn retain<P>(&mut self, keep: P)
+    where
+        P: FnMut(&Element) -> bool,
+    {
+        self.via_mut().retain(keep)
+    }
+
+    #[inline]
+    fn retain_mut<P>(&mut self, keep: P)
+    where
+        P: FnMut(&mut Element) -> bool,
+    {
+        self.via_mut().retain_mut(keep)
+    }
+
+    #[inline]
+    fn extract_if<P>(
+        &mut self,
+        extract: P,
+    ) -> IdentifiedVecExtractIf<
+        '_,
+        <Element as Identifiable>::ID,
+        Element,
+        Self::IdOfElement,
+        Self::Hasher,
+        P,
+    >
+    where
+        P: FnMut(&mut Element) -> bool,
+    {
+        self.via_mut().extract_if(extract)
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.via_mut().clear()
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.via_mut().truncate(len)
+    }
+
+    #[inline]
+    fn split_off(&mut self, at: usize) -> Self
+    where
+        Self::IdOfElement: Clone,
+        Self::Hasher: Clone,
+    {
+        Self::from_identified_vec_of(self.via_mut().split_off(at))
+    }
+
+    #[inline]
+    fn drain<R>(&mut self, range: R) -> alloc::vec::IntoIter<Element>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.via_mut().drain(range)
+    }
+
+    #[inline]
+    fn union(self, other: &Self, choice: ConflictResolutionChoice) -> Self
+    where
+        Element: Clone,
+    {
+        Self::from_identified_vec_of(self.into_via().union(other.via(), choice))
+    }
+
+    #[inline]
+    fn intersection(self, other: &Self) -> Self {
+        Self::from_identified_vec_of(self.into_via().intersection(other.via()))
+    }
+
+    #[inline]
+    fn difference(self, other: &Self) -> Self {
+        Self::from_identified_vec_of(self.into_via().difference(other.via()))
+    }
+
+    #[inline]
+    fn symmetric_difference(self, other: &Self) -> Self
+    where
+        Element: Clone,
+    {
+        Self::from_identified_vec_of(self.into_via().symmetric_difference(other.via()))
+    }
+
+    #[inline]
+    fn is_subset(&self, other: &Self) -> bool {
+        self.via().is_subset(other.via())
+    }
+
+    #[inline]
+    fn is_superset(&self, other: &Self) -> bool {
+        self.via().is_superset(other.via())
+    }
+
+    #[inline]
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.via().is_disjoint(other.via())
+    }
+
+    #[inline]
+    fn difference_from(
+        &self,
+        from: &Self,
+    ) -> IdentifiedVecDiff<<Element as Identifiable>::ID, Element>
+    where
+        Element: PartialEq + Clone,
+    {
+        self.via().difference_from(from.via())
+    }
+
+    #[inline]
+    fn apply(
+        &mut self,
+        diff: IdentifiedVecDiff<<Element as Identifiable>::ID, Element>,
+    ) -> Result<(), Error<Element::ID>> {
+        self.via_mut().apply(diff)
+    }
+
+    #[inline]
+    fn merge_three_way<R>(
+        &self,
+        base: &Self,
+        theirs: &Self,
+        resolve: R,
+    ) -> (
+        Self,
+        Vec<MergeConflict<<Element as Identifiable>::ID, Element>>,
+    )
+    where
+        Element: PartialEq + Clone,
+        Self::IdOfElement: Clone,
+        Self::Hasher: Clone,
+        R: FnMut(
+            &MergeConflict<<Element as Identifiable>::ID, Element>,
+        ) -> ConflictResolutionChoice,
+    {
+        let (merged, conflicts) = self
+            .via()
+            .merge_three_way(base.via(), theirs.via(), resolve);
+        (Self::from_identified_vec_of(merged), conflicts)
+    }
+
+    #[inline]
+    fn append_other_merging<It, M>(&mut self, other: It, merge: M)
+    where
+        It: IntoIterator<Item = Element>,
+        M: FnMut(&mut Element, Element),
+    {
+        self.via_mut().append_other_merging(other, merge)
+    }
+
+    #[inline]
+    fn union_with<M>(self, other: &Self, merge: M) -> Self
+    where
+        Element: Clone,
+        M: FnMut(&mut Element, Element),
+    {
+        Self::from_identified_vec_of(self.into_via().union_with(other.via(), merge))
+    }
 }
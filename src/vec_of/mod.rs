@@ -1,5 +1,7 @@
 mod errors;
+mod id_strategy;
 mod identifiable_trait;
+mod identified_vec_by;
 mod identified_vec_of;
 mod is_identified_vec_of;
 mod is_identified_vec_of_via;
@@ -7,11 +9,9 @@ mod newtype_identified_vec_of;
 mod primitives_identifiable;
 
 pub use errors::*;
+pub use id_strategy::*;
 pub use identifiable_trait::*;
+pub use identified_vec_by::*;
 pub use identified_vec_of::*;
 pub use is_identified_vec_of::*;
 pub use is_identified_vec_of_via::*;
-pub use newtype_identified_vec_of::*;
-
-#[cfg(feature = "id_prim")]
-pub use primitives_identifiable::*;
@@ -18,32 +18,212 @@
 //! ints.append(5);
 //! ```
 //!
+//! Optionally you can specify the `BuildHasher` the newtype should use to hash ids:
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{IsIdentifiableVecOfVia, ViaMarker, IsIdentifiedVec, IsIdentifiedVecOf, IdentifiedVec, IdentifiedVecOf, Identifiable, newtype_identified_vec};
+//! use std::collections::hash_map::DefaultHasher;
+//! use std::hash::BuildHasherDefault;
+//!
+//! newtype_identified_vec!(of: u32, named: Ints, hasher: BuildHasherDefault<DefaultHasher>);
+//!
+//! let mut ints = Ints::new();
+//! ints.append(5);
+//! ```
+//!
+//! With the `"serde"` feature, deserialization fails if two elements have the
+//! same id, you can instead choose the policy of one of the serde adapters
+//! `keep_first`, `keep_last` or `merge` in `identified_vec::serde`, after the
+//! optional hasher:
+//! ```
+//! extern crate identified_vec;
+//! use identified_vec::{IsIdentifiableVecOfVia, ViaMarker, IsIdentifiedVec, IsIdentifiedVecOf, IdentifiedVec, IdentifiedVecOf, Identifiable, newtype_identified_vec};
+//!
+//! // Deserializing `[5, 3, 5]` results in `[5, 3]`.
+//! newtype_identified_vec!(of: u32, named: Ints, duplicates: keep_first);
+//!
+//! let mut ints = Ints::new();
+//! ints.append(5);
+//! ```
+//!
 #[macro_export]
 macro_rules! newtype_identified_vec {
     (of: $item_ty: ty, named: $struct_name: ident) => {
-        #[derive(std::fmt::Debug, Clone, Eq, PartialEq)]
-        pub struct $struct_name(identified_vec::IdentifiedVecOf<$item_ty>);
+        identified_vec::newtype_identified_vec!(
+            of: $item_ty,
+            named: $struct_name,
+            hasher: identified_vec::__private::RandomState
+        );
+    };
+    (of: $item_ty: ty, named: $struct_name: ident, duplicates: $policy: ident) => {
+        identified_vec::newtype_identified_vec!(
+            of: $item_ty,
+            named: $struct_name,
+            hasher: identified_vec::__private::RandomState,
+            duplicates: $policy
+        );
+    };
+    (of: $item_ty: ty, named: $struct_name: ident, hasher: $hasher_ty: ty) => {
+        identified_vec::newtype_identified_vec!(
+            @newtype of: $item_ty,
+            named: $struct_name,
+            hasher: $hasher_ty
+        );
+
+        #[cfg(any(test, feature = "serde"))]
+        impl<'de> serde::Deserialize<'de> for $struct_name
+        where
+            $item_ty:
+                serde::Deserialize<'de> + identified_vec::Identifiable + core::fmt::Debug + Clone,
+        {
+            #[cfg(not(tarpaulin_include))] // false negative
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$struct_name, D::Error> {
+                let id_vec_of = identified_vec::IdentifiedVecOf::<$item_ty, $hasher_ty>::deserialize(
+                    deserializer,
+                )?;
+                use identified_vec::IsIdentifiableVecOfVia;
+                return Ok(Self::from_identified_vec_of(id_vec_of));
+            }
+        }
+    };
+    (of: $item_ty: ty, named: $struct_name: ident, hasher: $hasher_ty: ty, duplicates: $policy: ident) => {
+        identified_vec::newtype_identified_vec!(
+            @newtype of: $item_ty,
+            named: $struct_name,
+            hasher: $hasher_ty
+        );
+
+        #[cfg(any(test, feature = "serde"))]
+        impl<'de> serde::Deserialize<'de> for $struct_name
+        where
+            $item_ty: serde::Deserialize<'de> + identified_vec::Identifiable,
+        {
+            #[cfg(not(tarpaulin_include))] // false negative
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$struct_name, D::Error> {
+                let id_vec_of = identified_vec::serde::$policy::deserialize::<
+                    $item_ty,
+                    $hasher_ty,
+                    D,
+                >(deserializer)?;
+                use identified_vec::IsIdentifiableVecOfVia;
+                return Ok(Self::from_identified_vec_of(id_vec_of));
+            }
+        }
+    };
+    (@newtype of: $item_ty: ty, named: $struct_name: ident, hasher: $hasher_ty: ty) => {
+        #[derive(core::fmt::Debug, Clone, Eq, PartialEq)]
+        pub struct $struct_name(identified_vec::IdentifiedVecOf<$item_ty, $hasher_ty>);
 
         impl identified_vec::ViaMarker for $struct_name {}
         impl identified_vec::IsIdentifiableVecOfVia<$item_ty> for $struct_name {
-            fn via_mut(&mut self) -> &mut identified_vec::IdentifiedVecOf<$item_ty> {
+            type Hasher = $hasher_ty;
+
+            fn via_mut(&mut self) -> &mut identified_vec::IdentifiedVecOf<$item_ty, $hasher_ty> {
                 &mut self.0
             }
 
-            fn via(&self) -> &identified_vec::IdentifiedVecOf<$item_ty> {
+            fn via(&self) -> &identified_vec::IdentifiedVecOf<$item_ty, $hasher_ty> {
                 &self.0
             }
 
+            fn into_via(self) -> identified_vec::IdentifiedVecOf<$item_ty, $hasher_ty> {
+                self.0
+            }
+
             fn from_identified_vec_of(
-                identified_vec_of: identified_vec::IdentifiedVecOf<$item_ty>,
+                identified_vec_of: identified_vec::IdentifiedVecOf<$item_ty, $hasher_ty>,
             ) -> Self {
                 Self(identified_vec_of)
             }
         }
 
-        impl std::fmt::Display for $struct_name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                std::fmt::Display::fmt(&self.0, f)
+        impl $struct_name {
+            /// Creates a new collection from the elements in the given sequence,
+            /// ignoring any element whose id is already present.
+            ///
+            /// Inherent, so that `from_iter` is not ambiguous between
+            /// `FromIterator` and `IsIdentifiedVecOf`, which behave the same.
+            #[allow(clippy::should_implement_trait)]
+            #[inline]
+            pub fn from_iter<It>(unique_elements: It) -> Self
+            where
+                It: IntoIterator<Item = $item_ty>,
+            {
+                <Self as core::iter::FromIterator<$item_ty>>::from_iter(unique_elements)
+            }
+        }
+
+        impl Default for $struct_name {
+            fn default() -> Self {
+                Self(Default::default())
+            }
+        }
+
+        impl core::iter::FromIterator<$item_ty> for $struct_name {
+            fn from_iter<It: IntoIterator<Item = $item_ty>>(iter: It) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        impl Extend<$item_ty> for $struct_name {
+            fn extend<It: IntoIterator<Item = $item_ty>>(&mut self, iter: It) {
+                self.0.extend(iter)
+            }
+        }
+
+        impl From<identified_vec::__private::Vec<$item_ty>> for $struct_name {
+            fn from(elements: identified_vec::__private::Vec<$item_ty>) -> Self {
+                Self(elements.into())
+            }
+        }
+
+        impl<const N: usize> From<[$item_ty; N]> for $struct_name {
+            fn from(elements: [$item_ty; N]) -> Self {
+                Self(elements.into())
+            }
+        }
+
+        impl core::ops::Index<usize> for $struct_name {
+            type Output = $item_ty;
+
+            fn index(&self, index: usize) -> &$item_ty {
+                &self.0[index]
+            }
+        }
+
+        impl core::ops::Index<&<$item_ty as identified_vec::Identifiable>::ID> for $struct_name {
+            type Output = $item_ty;
+
+            fn index(&self, id: &<$item_ty as identified_vec::Identifiable>::ID) -> &$item_ty {
+                &self.0[id]
+            }
+        }
+
+        impl PartialEq<[$item_ty]> for $struct_name {
+            fn eq(&self, other: &[$item_ty]) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<identified_vec::__private::Vec<$item_ty>> for $struct_name {
+            fn eq(&self, other: &identified_vec::__private::Vec<$item_ty>) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const N: usize> PartialEq<[$item_ty; N]> for $struct_name {
+            fn eq(&self, other: &[$item_ty; N]) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl core::fmt::Display for $struct_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
             }
         }
 
@@ -52,6 +232,7 @@ macro_rules! newtype_identified_vec {
             type IntoIter = identified_vec::identified_vec_into_iterator::IdentifiedVecIntoIterator<
                 <$item_ty as identified_vec::Identifiable>::ID,
                 $item_ty,
+                $hasher_ty,
             >;
 
             fn into_iter(self) -> Self::IntoIter {
@@ -59,10 +240,24 @@ macro_rules! newtype_identified_vec {
             }
         }
 
+        impl<'a> IntoIterator for &'a $struct_name {
+            type Item = &'a $item_ty;
+            type IntoIter = identified_vec::identified_vec_iterator::IdentifiedVecIterator<
+                'a,
+                <$item_ty as identified_vec::Identifiable>::ID,
+                $item_ty,
+                $hasher_ty,
+            >;
+
+            fn into_iter(self) -> Self::IntoIter {
+                Self::IntoIter::new(&self.0)
+            }
+        }
+
         #[cfg(any(test, feature = "serde"))]
         impl serde::Serialize for $struct_name
         where
-            $item_ty: serde::Serialize + identified_vec::Identifiable + std::fmt::Debug + Clone,
+            $item_ty: serde::Serialize + identified_vec::Identifiable + core::fmt::Debug + Clone,
         {
             fn serialize<S>(
                 &self,
@@ -74,22 +269,5 @@ macro_rules! newtype_identified_vec {
                 identified_vec::IdentifiedVecOf::serialize(&self.0, serializer)
             }
         }
-
-        #[cfg(any(test, feature = "serde"))]
-        impl<'de> serde::Deserialize<'de> for $struct_name
-        where
-            $item_ty:
-                serde::Deserialize<'de> + identified_vec::Identifiable + std::fmt::Debug + Clone,
-        {
-            #[cfg(not(tarpaulin_include))] // false negative
-            fn deserialize<D: serde::Deserializer<'de>>(
-                deserializer: D,
-            ) -> Result<$struct_name, D::Error> {
-                let id_vec_of =
-                    identified_vec::IdentifiedVecOf::<$item_ty>::deserialize(deserializer)?;
-                use identified_vec::IsIdentifiableVecOfVia;
-                return Ok(Self::from_identified_vec_of(id_vec_of));
-            }
-        }
     };
 }
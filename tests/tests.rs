@@ -1,8 +1,24 @@
-use std::{cell::RefCell, collections::HashSet, fmt::Debug, ops::Deref};
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::clone_on_copy,
+    clippy::redundant_closure,
+    clippy::upper_case_acronyms,
+    clippy::useless_format
+)]
+
+use std::{
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, HashSet},
+    fmt::Debug,
+    hash::BuildHasherDefault,
+    ops::Deref,
+};
 
 use identified_vec::{
-    newtype_identified_vec, ConflictResolutionChoice, Error, Identifiable, IdentifiedVec,
-    IdentifiedVecOf, IdentifiedVecOfSerdeFailure, IsIdentifiedVec, IsIdentifiedVecOf, ItemsCloned,
+    newtype_identified_vec, ByIdentifiable, ConflictResolutionChoice, Entry, ErasedError, Error,
+    IdStrategy, Identifiable, IdentifiedVec, IdentifiedVecBy, IdentifiedVecById,
+    IdentifiedVecChange, IdentifiedVecOf, IdentifiedVecOfSerdeFailure, IdentifiedVecSeed,
+    IsIdentifiedVec, IsIdentifiedVecOf, ItemsCloned,
 };
 #[cfg(any(test, feature = "serde"))]
 use serde::{Deserialize, Serialize};
@@ -94,6 +110,21 @@ fn into_iter_identified_vec() {
     }
 }
 
+#[test]
+fn into_iter_rev_and_len() {
+    let identified_vec = SUT::from_iter([1, 2, 3, 4]);
+    let mut into_iter = identified_vec.clone().into_iter();
+    assert_eq!(into_iter.len(), 4);
+    assert_eq!(into_iter.next(), Some(1));
+    assert_eq!(into_iter.next_back(), Some(4));
+    assert_eq!(into_iter.len(), 2);
+    assert_eq!(into_iter.collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(
+        identified_vec.into_iter().rev().collect::<Vec<_>>(),
+        [4, 3, 2, 1]
+    );
+}
+
 #[test]
 fn iter() {
     let vec = vec![User::blob(), User::blob_jr(), User::blob_sr()];
@@ -103,6 +134,29 @@ fn iter() {
     }
 }
 
+#[test]
+fn iter_rev_len_and_ref_into_iter() {
+    let identified_vec = SUT::from_iter([1, 2, 3]);
+    let mut iter = identified_vec.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.collect::<Vec<_>>(), [&1, &2]);
+    assert_eq!(
+        identified_vec.iter().rev().copied().collect::<Vec<_>>(),
+        [3, 2, 1]
+    );
+
+    let mut sum = 0;
+    for element in &identified_vec {
+        sum += element;
+    }
+    assert_eq!(sum, 6);
+
+    let identified_vec_of = IdentifiedVecOf::<u32>::from_iter([4, 5]);
+    assert_eq!((&identified_vec_of).into_iter().nth(1), Some(&5));
+}
+
 #[test]
 fn get() {
     let vec = vec![User::blob(), User::blob_jr(), User::blob_sr()];
@@ -145,6 +199,73 @@ fn get() {
     );
 }
 
+#[test]
+fn get_mut() {
+    let mut identified_vec = Users::from_iter([User::blob(), User::blob_jr()]);
+    identified_vec
+        .get_mut(&2)
+        .unwrap()
+        .name
+        .borrow_mut()
+        .push_str(" Esq.");
+    assert_eq!(
+        identified_vec.get(&2),
+        Some(&User::new(2, "Blob, Jr. Esq."))
+    );
+    assert!(identified_vec.get_mut(&999).is_none());
+
+    *identified_vec.get_at_index_mut(0).unwrap() = User::new(1, "Blobby");
+    assert_eq!(identified_vec.get(&1), Some(&User::new(1, "Blobby")));
+    assert!(identified_vec.get_at_index_mut(999).is_none());
+}
+
+#[test]
+#[should_panic(expected = "The id of an element must not be mutated")]
+fn get_mut_changing_id_panics() {
+    let mut identified_vec = Users::from_iter([User::blob(), User::blob_jr()]);
+    identified_vec.get_mut(&1).unwrap().id = 3;
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn get_mut_changing_id_while_panicking_does_not_panic_again() {
+    let mut identified_vec = Users::from_iter([User::blob(), User::blob_jr()]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut user = identified_vec.get_mut(&1).unwrap();
+        user.id = 3;
+        panic!("boom");
+    }));
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+}
+
+#[test]
+fn iter_mut_and_values_mut() {
+    let mut identified_vec =
+        IdentifiedVecOf::<User>::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    identified_vec
+        .iter_mut()
+        .rev()
+        .for_each(|u| u.name.borrow_mut().make_ascii_uppercase());
+    assert_eq!(
+        identified_vec.items(),
+        [
+            User::new(1, "BLOB"),
+            User::new(2, "BLOB, JR."),
+            User::new(3, "BLOB, SR.")
+        ]
+    );
+    assert_eq!(identified_vec.iter_mut().len(), 3);
+
+    let mut values = identified_vec.values_mut();
+    *values[1] = User::new(2, "Junior");
+    drop(values);
+    assert_eq!(
+        identified_vec.get_at_index(1),
+        Some(&User::new(2, "Junior"))
+    );
+}
+
 #[test]
 fn contains_element() {
     let identified_vec = SUT::from_iter([1, 2, 3]);
@@ -177,6 +298,26 @@ fn index_id() {
     assert_eq!(identified_vec.index_of_id(&2), Some(1));
 }
 
+#[test]
+fn index_of_id_after_insert_and_remove() {
+    let mut identified_vec = SUT::from_iter([1, 2, 3, 4]);
+    identified_vec.insert(0, 1);
+    assert_eq!(identified_vec.index_of_id(&0), Some(1));
+    assert_eq!(identified_vec.index_of_id(&4), Some(4));
+    identified_vec.remove_by_id(&2);
+    assert_eq!(identified_vec.index_of_id(&2), None);
+    assert_eq!(identified_vec.index_of_id(&3), Some(2));
+    identified_vec.remove_at(0);
+    assert_eq!(
+        identified_vec
+            .ids()
+            .iter()
+            .map(|id| identified_vec.index_of_id(id))
+            .collect::<Vec<_>>(),
+        [Some(0), Some(1), Some(2)]
+    );
+}
+
 #[test]
 fn remove_element() {
     let mut identified_vec = SUT::from_iter([1, 2, 3]);
@@ -235,6 +376,29 @@ fn constructor_from_iter_select_unique_ids_with() {
     )
 }
 
+#[test]
+fn capturing_closure_as_id_of_element() {
+    let tenant = String::from("acme");
+    let mut identified_vec =
+        IdentifiedVec::<String, &str, _>::new_identifying_element(move |e: &&str| {
+            format!("{}/{}", tenant, e.to_lowercase())
+        });
+    identified_vec.append("Foo");
+    assert_eq!(identified_vec.append("FOO"), (false, 0));
+    identified_vec.append("Bar");
+    assert_eq!(identified_vec.ids(), ["acme/foo", "acme/bar"]);
+    assert_eq!(identified_vec.get(&"acme/bar".to_string()), Some(&"Bar"));
+
+    let offset = 10;
+    let from_iter = IdentifiedVec::<i32, i32, _>::from_iter_select_unique_ids_with(
+        [1, 2, 1],
+        |e: &i32| e + offset,
+        |_| ConflictResolutionChoice::ChooseFirst,
+    );
+    assert_eq!(from_iter.ids(), [11, 12]);
+    assert_eq!(from_iter.clone(), from_iter);
+}
+
 #[test]
 fn constructor_from_iter_select_unique_with() {
     #[derive(Eq, PartialEq, Clone, Hash, Debug)]
@@ -314,14 +478,20 @@ fn try_append_unique_element() {
     let mut identified_vec = SUT::from_iter([1, 2, 3]);
     let result = identified_vec.try_append_unique_element(2);
     assert!(result.is_err());
-    assert_eq!(result, Err(Error::ElementWithSameValueFound(format!("2"))));
+    assert_eq!(
+        result,
+        Err(Error::ElementWithSameValueFound { id: 2, index: 1 })
+    );
     assert_eq!(identified_vec.items(), [1, 2, 3]);
 
     let mut identified_vec =
         IdentifiedVecOf::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
     let result = identified_vec.try_append_unique_element(User::new(2, "Blob blob Jr"));
     assert!(result.is_err());
-    assert_eq!(result, Err(Error::ElementWithSameIDFound(format!("2"))));
+    assert_eq!(
+        result,
+        Err(Error::ElementWithSameIDFound { id: 2, index: 1 })
+    );
     assert_eq!(
         identified_vec.items(),
         [User::blob(), User::blob_jr(), User::blob_sr()]
@@ -362,7 +532,10 @@ fn try_append_element_with_existing_id() {
     identified_vec.append(User::blob_sr());
     let result = identified_vec.try_append_new(User::new(2, "Blob Jr Jr"));
     assert!(result.is_err());
-    assert_eq!(result, Err(Error::ElementWithSameIDFound(format!("2"))));
+    assert_eq!(
+        result,
+        Err(Error::ElementWithSameIDFound { id: 2, index: 1 })
+    );
     assert_eq!(
         identified_vec.items(),
         [User::blob(), User::blob_jr(), User::blob_sr()]
@@ -485,6 +658,43 @@ fn update_at_expect_panic_other_id() {
     identified_vec.update_at(User::new(999, "Zelda"), 0);
 }
 
+#[test]
+fn try_update_at() {
+    let mut identified_vec = Users::from_iter([User::blob(), User::blob_jr()]);
+    assert_eq!(
+        identified_vec.try_update_at(User::new(2, "Blob, Jr. Jr."), 1),
+        Ok(User::blob_jr())
+    );
+    assert_eq!(
+        identified_vec.try_update_at(User::blob_sr(), 2),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+        identified_vec.try_update_at(User::blob_sr(), 0),
+        Err(Error::IdMismatch {
+            expected: 1,
+            found: 3,
+            index: 0
+        })
+    );
+    assert_eq!(
+        identified_vec.items(),
+        [User::blob(), User::new(2, "Blob, Jr. Jr.")]
+    );
+}
+
+#[test]
+fn try_insert() {
+    let mut identified_vec = SUT::from_iter([1, 2, 3]);
+    assert_eq!(identified_vec.try_insert(0, 3), Ok((true, 3)));
+    assert_eq!(identified_vec.try_insert(2, 99), Ok((false, 1)));
+    assert_eq!(
+        identified_vec.try_insert(4, 5),
+        Err(Error::IndexOutOfBounds { index: 5, len: 4 })
+    );
+    assert_eq!(identified_vec.items(), [1, 2, 3, 0]);
+}
+
 #[test]
 fn update_or_append() {
     let mut identified_vec = SUT::from_iter([1, 2, 3]);
@@ -498,7 +708,7 @@ fn try_update() {
     let mut identified_vec = SUT::from_iter([1, 2, 3]);
     assert_eq!(
         identified_vec.try_update(4),
-        Err(Error::ExpectedElementNotPresent(format!("4")))
+        Err(Error::ExpectedElementNotPresent { id: 4 })
     );
     assert_eq!(identified_vec.items(), [1, 2, 3]);
 
@@ -531,10 +741,45 @@ fn update_or_insert() {
 #[test]
 fn remove_at_offsets() {
     let mut identified_vec = SUT::from_iter([1, 2, 3]);
-    identified_vec.remove_at_offsets([0, 2]);
+    identified_vec.remove_at_offsets([2, 0, 2]);
     assert_eq!(identified_vec.items(), [2])
 }
 
+#[test]
+#[should_panic(expected = "Precondition failure, index out of bounds")]
+fn remove_at_offsets_out_of_bounds_panics() {
+    let mut identified_vec = SUT::from_iter([1, 2, 3]);
+    identified_vec.remove_at_offsets([0, 3]);
+}
+
+#[test]
+fn try_remove_at() {
+    let mut identified_vec = SUT::from_iter([1, 2, 3]);
+    assert_eq!(identified_vec.try_remove_at(1), Ok(2));
+    assert_eq!(
+        identified_vec.try_remove_at(2),
+        Err(Error::IndexOutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(identified_vec.items(), [1, 3]);
+}
+
+#[test]
+fn try_remove_at_offsets() {
+    let mut identified_vec = SUT::from_iter([1, 2, 3, 4]);
+    assert_eq!(
+        identified_vec.try_remove_at_offsets([0, 4, 9]),
+        Err(Error::IndexOutOfBounds { index: 4, len: 4 })
+    );
+    assert_eq!(identified_vec.items(), [1, 2, 3, 4]);
+    assert_eq!(identified_vec.try_remove_at_offsets([3, 0, 3]), Ok(()));
+    assert_eq!(identified_vec.items(), [2, 3]);
+    assert_eq!(identified_vec.index_of_id(&3), Some(1));
+    assert_eq!(identified_vec.get(&1), None);
+    assert_eq!(identified_vec.try_remove_at_offsets([]), Ok(()));
+    assert_eq!(identified_vec.try_remove_at_offsets([1, 0]), Ok(()));
+    assert!(identified_vec.is_empty());
+}
+
 #[test]
 #[should_panic(expected = "Precondition failure, index out of bounds")]
 fn remove_at_out_of_bounds() {
@@ -694,6 +939,31 @@ fn eq() {
     });
 }
 
+#[test]
+fn custom_hasher() {
+    type Hasher = BuildHasherDefault<DefaultHasher>;
+
+    let mut identified_vec =
+        IdentifiedVecOf::<User, Hasher>::with_capacity_and_hasher(User::id, 3, Hasher::default());
+    identified_vec.append(User::blob());
+    identified_vec.append(User::blob_jr());
+    assert_eq!(identified_vec.append(User::blob()), (false, 0));
+    assert_eq!(identified_vec.items(), [User::blob(), User::blob_jr()]);
+
+    let defaulted = IdentifiedVecOf::<User, Hasher>::new_identifying_element(User::id);
+    assert!(defaulted.is_empty());
+
+    newtype_identified_vec!(of: u32, named: HashedInts, hasher: Hasher);
+    let ints = HashedInts::from_iter([3, 1, 3, 2]);
+    assert_eq!(ints.items(), [3, 1, 2]);
+    assert_eq!(ints.iter().rev().collect::<Vec<_>>(), [&2, &1, &3]);
+    assert_eq!(
+        serde_json::from_str::<HashedInts>("[3,1,2]").unwrap(),
+        ints.clone()
+    );
+    assert_eq!(serde_json::to_string(&ints).unwrap(), "[3,1,2]");
+}
+
 #[test]
 fn display() {
     let identified_vec = SUT::from_iter([1, 2, 3]);
@@ -725,3 +995,1008 @@ fn test_macro() {
     sut.update_or_append(User::blob_jr());
     assert_eq!(sut.items(), [User::blob_sr(), User::blob_jr()]);
 }
+
+#[test]
+fn sort() {
+    let mut sut = SUT::from_iter([3, 1, 2]);
+    sut.sort();
+    assert_eq!(sut.items(), [1, 2, 3]);
+    assert_eq!(sut.index_of_id(&3), Some(2));
+    sut.sort_by(|a, b| b.cmp(a));
+    assert_eq!(sut.items(), [3, 2, 1]);
+    assert_eq!(sut.index_of_id(&1), Some(2));
+    sut.sort_unstable_by(|a, b| a.cmp(b));
+    assert_eq!(sut.items(), [1, 2, 3]);
+    sut.sort_by_key(|e| *e % 2);
+    assert_eq!(sut.items(), [2, 1, 3]);
+    sut.sort_by_id();
+    assert_eq!(sut.items(), [1, 2, 3]);
+}
+
+#[test]
+fn sort_by_cached_key_keeps_identity() {
+    let mut users = Users::from_iter([User::blob_sr(), User::blob(), User::blob_jr()]);
+    let mut calls = 0;
+    users.sort_by_cached_key(|u| {
+        calls += 1;
+        u.name.borrow().len()
+    });
+    assert_eq!(calls, 3);
+    assert_eq!(users.ids(), [1, 3, 2]);
+    assert_eq!(users.index_of_id(&2), Some(2));
+    assert_eq!(users.get(&2), Some(&User::blob_jr()));
+}
+
+#[test]
+fn move_element_and_move_from_offsets() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5]);
+    sut.move_element(4, 1);
+    assert_eq!(sut.items(), [1, 5, 2, 3, 4]);
+    assert_eq!(sut.index_of_id(&4), Some(4));
+    sut.move_element(1, 4);
+    assert_eq!(sut.items(), [1, 2, 3, 4, 5]);
+    sut.move_from_offsets([3, 1], 0);
+    assert_eq!(sut.items(), [2, 4, 1, 3, 5]);
+    sut.move_from_offsets([0, 1], 5);
+    assert_eq!(sut.items(), [1, 3, 5, 2, 4]);
+    assert_eq!(sut.index_of_id(&2), Some(3));
+}
+
+#[test]
+#[should_panic(expected = "Precondition failure, index out of bounds")]
+fn move_from_offsets_out_of_bounds() {
+    SUT::from_iter([1, 2, 3]).move_from_offsets([3], 0);
+}
+
+#[test]
+fn swap_reverse_and_rotate() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4]);
+    sut.swap_at(0, 3);
+    assert_eq!(sut.items(), [4, 2, 3, 1]);
+    assert!(sut.swap_ids(&4, &1));
+    assert!(!sut.swap_ids(&4, &9));
+    assert_eq!(sut.items(), [1, 2, 3, 4]);
+    sut.reverse();
+    assert_eq!(sut.items(), [4, 3, 2, 1]);
+    sut.rotate_left(1);
+    assert_eq!(sut.items(), [3, 2, 1, 4]);
+    sut.rotate_right(2);
+    assert_eq!(sut.items(), [1, 4, 3, 2]);
+    assert_eq!(
+        sut.ids()
+            .iter()
+            .map(|id| sut.index_of_id(id))
+            .collect::<Vec<_>>(),
+        [Some(0), Some(1), Some(2), Some(3)]
+    );
+}
+
+#[test]
+fn retain_and_retain_mut() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5, 6]);
+    sut.retain(|e| e % 3 != 0);
+    assert_eq!(sut.items(), [1, 2, 4, 5]);
+    assert_eq!(sut.index_of_id(&5), Some(3));
+    assert_eq!(sut.index_of_id(&3), None);
+
+    let mut users = Users::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    users.retain_mut(|u| {
+        u.name.borrow_mut().push('!');
+        u.id != 2
+    });
+    assert_eq!(users.ids(), [1, 3]);
+    assert_eq!(*users.get(&3).unwrap().name.borrow(), "Blob, Sr.!");
+    assert_eq!(users.index_of_id(&3), Some(1));
+}
+
+#[test]
+fn extract_if() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        sut.extract_if(|e| *e % 2 == 0).collect::<Vec<_>>(),
+        [2, 4, 6]
+    );
+    assert_eq!(sut.items(), [1, 3, 5]);
+    assert_eq!(sut.index_of_id(&5), Some(2));
+
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5, 6]);
+    let mut iter = sut.extract_if(|e| *e % 2 == 0);
+    assert_eq!(iter.next(), Some(2));
+    drop(iter);
+    assert_eq!(sut.items(), [1, 3, 4, 5, 6]);
+    assert_eq!(sut.index_of_id(&6), Some(4));
+    sut.append(7);
+    assert_eq!(sut.index_of_id(&7), Some(5));
+}
+
+#[test]
+fn extract_if_forgotten_leaves_consistent_vec() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5, 6]);
+    let mut iter = sut.extract_if(|e| *e % 2 == 0);
+    assert_eq!(iter.next(), Some(2));
+    std::mem::forget(iter);
+    assert!(sut.is_empty());
+    assert!(sut.elements().is_empty());
+    assert_eq!(sut.get_at_index(0), None);
+    assert_eq!(sut.contains_id(&3), false);
+    sut.append(3);
+    assert_eq!(sut.index_of_id(&3), Some(0));
+    assert_eq!(sut.items(), [3]);
+}
+
+#[test]
+fn retain_mut() {
+    let mut users = Users::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    users.retain_mut(|user| {
+        user.name.get_mut().push('!');
+        user.id != 2
+    });
+    assert_eq!(
+        users.items(),
+        [User::new(1, "Blob!"), User::new(3, "Blob, Sr.!")]
+    );
+    assert_eq!(users.index_of_id(&3), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "The id of an element must not be mutated")]
+fn retain_mut_changing_id_panics() {
+    let mut users = Users::from_iter([User::blob()]);
+    users.retain_mut(|user| {
+        user.id = 2;
+        true
+    });
+}
+
+#[test]
+fn extract_if_keeps_element_when_predicate_panics() {
+    let mut sut = SUT::from_iter([1, 2, 3]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        sut.extract_if(|e| if *e == 2 { panic!("boom") } else { false })
+            .for_each(drop);
+    }));
+    assert!(result.is_err());
+    assert_eq!(sut.items(), [1, 2, 3]);
+    assert_eq!(sut.index_of_id(&3), Some(2));
+}
+
+#[test]
+fn clear_and_truncate() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4]);
+    sut.truncate(5);
+    assert_eq!(sut.len(), 4);
+    sut.truncate(2);
+    assert_eq!(sut.items(), [1, 2]);
+    assert_eq!(sut.contains_id(&3), false);
+    sut.append(3);
+    assert_eq!(sut.index_of_id(&3), Some(2));
+    sut.clear();
+    assert!(sut.is_empty());
+    assert_eq!(sut.index_of_id(&1), None);
+}
+
+#[test]
+fn split_off() {
+    let mut users = Users::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    let tail: Users = users.split_off(1);
+    assert_eq!(users.items(), [User::blob()]);
+    assert_eq!(tail.items(), [User::blob_jr(), User::blob_sr()]);
+    assert_eq!(tail.index_of_id(&3), Some(1));
+
+    let mut identified_vec = IdentifiedVec::<i32, i32, _>::new_identifying_element(|e| *e * 10);
+    identified_vec.append_other([1, 2, 3]);
+    let tail = identified_vec.split_off(3);
+    assert!(tail.is_empty());
+    assert_eq!(identified_vec.ids(), [10, 20, 30]);
+}
+
+#[test]
+fn drain() {
+    let mut sut = SUT::from_iter([1, 2, 3, 4, 5]);
+    assert_eq!(sut.drain(1..=2).rev().collect::<Vec<_>>(), [3, 2]);
+    assert_eq!(sut.items(), [1, 4, 5]);
+    assert_eq!(sut.index_of_id(&5), Some(2));
+    assert_eq!(sut.drain(..).collect::<Vec<_>>(), [1, 4, 5]);
+    assert!(sut.is_empty());
+}
+
+#[test]
+fn union() {
+    let local = Users::from_iter([User::blob_sr(), User::blob()]);
+    let remote = Users::from_iter([User::new(1, "Blob, remote"), User::blob_jr()]);
+    assert_eq!(
+        local
+            .clone()
+            .union(&remote, ConflictResolutionChoice::ChooseFirst)
+            .items(),
+        [User::blob_sr(), User::blob(), User::blob_jr()]
+    );
+    let union = local.union(&remote, ConflictResolutionChoice::ChooseLast);
+    assert_eq!(
+        union.items(),
+        [
+            User::blob_sr(),
+            User::new(1, "Blob, remote"),
+            User::blob_jr()
+        ]
+    );
+    assert_eq!(union.index_of_id(&2), Some(2));
+}
+
+#[test]
+fn intersection_difference_and_symmetric_difference() {
+    let lhs = SUT::from_iter([5, 1, 2, 3]);
+    let rhs = SUT::from_iter([4, 3, 6, 1]);
+    assert_eq!(lhs.clone().intersection(&rhs).items(), [1, 3]);
+    assert_eq!(lhs.clone().difference(&rhs).items(), [5, 2]);
+    let symmetric_difference = lhs.symmetric_difference(&rhs);
+    assert_eq!(symmetric_difference.items(), [5, 2, 4, 6]);
+    assert_eq!(symmetric_difference.index_of_id(&6), Some(3));
+}
+
+#[test]
+fn set_operations_compare_ids_of_self() {
+    let mut tens = IdentifiedVec::<u32, u32>::new_identifying_element(|e| e / 10);
+    tens.append_other([10, 21, 35]);
+    let mut ones = IdentifiedVec::<u32, u32>::new_identifying_element(|e| e % 10);
+    ones.append_other([41, 12]);
+    assert_eq!(tens.clone().intersection(&ones).items(), [10, 21]);
+    assert_eq!(tens.clone().difference(&ones).items(), [35]);
+    assert_eq!(tens.symmetric_difference(&ones).items(), [35]);
+}
+
+#[test]
+fn subset_superset_and_disjoint() {
+    let small = SUT::from_iter([2, 1]);
+    let large = SUT::from_iter([1, 2, 3]);
+    let other = SUT::from_iter([4, 5]);
+    assert!(small.is_subset(&large));
+    assert!(!large.is_subset(&small));
+    assert!(large.is_superset(&small));
+    assert!(small.is_disjoint(&other));
+    assert!(!small.is_disjoint(&large));
+    assert!(SUT::new().is_subset(&small));
+}
+
+#[test]
+fn difference_from_and_apply() {
+    let old = Users::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    let new = Users::from_iter([
+        User::blob_sr(),
+        User::new(4, "Blob, III"),
+        User::new(1, "Blob, updated"),
+    ]);
+    let diff = new.difference_from(&old);
+    assert_eq!(
+        diff.changes(),
+        [
+            IdentifiedVecChange::Remove {
+                offset: 1,
+                id: 2,
+                element: User::blob_jr()
+            },
+            IdentifiedVecChange::Insert {
+                offset: 1,
+                id: 4,
+                element: User::new(4, "Blob, III")
+            },
+            IdentifiedVecChange::Move {
+                from: 0,
+                to: 2,
+                id: 1
+            },
+            IdentifiedVecChange::Update {
+                offset: 2,
+                id: 1,
+                old: User::blob(),
+                new: User::new(1, "Blob, updated")
+            },
+        ]
+    );
+    let mut synced = old.clone();
+    synced.apply(diff).unwrap();
+    assert_eq!(synced, new);
+    assert_eq!(synced.index_of_id(&1), Some(2));
+    assert!(new.difference_from(&new).is_empty());
+}
+
+#[test]
+fn difference_from_and_apply_round_trips() {
+    let versions = [
+        vec![],
+        vec![1, 2, 3, 4, 5, 6],
+        vec![6, 5, 4, 3, 2, 1],
+        vec![2, 4, 6, 8],
+        vec![8, 1, 7, 2, 6],
+        vec![3, 1, 4, 5, 9, 2, 6],
+    ];
+    for from in versions.iter() {
+        for to in versions.iter() {
+            let from = SUT::from_iter(from.clone());
+            let to = SUT::from_iter(to.clone());
+            let mut applied = from.clone();
+            applied.apply(to.difference_from(&from)).unwrap();
+            assert_eq!(applied, to);
+            assert_eq!(
+                applied
+                    .ids()
+                    .iter()
+                    .map(|id| applied.index_of_id(id))
+                    .collect::<Vec<_>>(),
+                (0..to.len()).map(Some).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn error_carries_typed_id() {
+    let mut users = IdentifiedVecOf::from_iter([User::blob(), User::blob_jr()]);
+    let error = users
+        .try_append_new(User::new(2, "Blob, Jr. Jr."))
+        .unwrap_err();
+    match &error {
+        Error::ElementWithSameIDFound { id, index } => assert_eq!((*id, *index), (2, 1)),
+        _ => panic!("unexpected error {error:?}"),
+    }
+    assert_eq!(
+        error.to_string(),
+        "Duplicate element with same ID: `2` found at index 1"
+    );
+    let erased: ErasedError = error.into();
+    assert_eq!(
+        erased.message,
+        "Duplicate element with same ID: `2` found at index 1"
+    );
+
+    let mut strings = IdentifiedVec::<String, String>::new_identifying_element(|s| s.clone());
+    let error = strings.try_update("multi\nline".to_string()).unwrap_err();
+    assert_eq!(
+        ErasedError::from(error).to_string(),
+        "Element with that id: `\"multi\\nline\"` not found in collection"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn errors_are_std_errors() {
+    fn boxed<E: std::error::Error + 'static>(error: E) -> Box<dyn std::error::Error> {
+        Box::new(error)
+    }
+    let error = boxed(Error::IndexOutOfBounds::<u16> { index: 3, len: 2 });
+    assert_eq!(error.to_string(), "Index 3 out of bounds for length 2");
+    let erased = boxed(ErasedError::from(Error::ExpectedElementNotPresent {
+        id: 7,
+    }));
+    assert_eq!(
+        erased.to_string(),
+        "Element with that id: `7` not found in collection"
+    );
+    let failure = boxed(IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(1));
+    assert_eq!(failure.to_string(), "Duplicate element at offset 1");
+}
+
+#[test]
+fn apply_fails_without_modifying() {
+    let old = SUT::from_iter([1, 2]);
+    let new = SUT::from_iter([2, 3]);
+    let diff = new.difference_from(&old);
+    let mut sut = SUT::from_iter([2]);
+    assert_eq!(
+        sut.apply(diff.clone()),
+        Err(Error::ExpectedElementNotPresent { id: 1 })
+    );
+    assert_eq!(sut.items(), [2]);
+    let mut sut = SUT::from_iter([1, 2, 3]);
+    assert_eq!(
+        sut.apply(diff),
+        Err(Error::ElementWithSameIDFound { id: 3, index: 2 })
+    );
+    assert_eq!(sut.items(), [1, 2, 3]);
+}
+
+#[test]
+fn merge_three_way() {
+    let base = SUT::from_iter([1, 2, 3, 4]);
+    let ours = SUT::from_iter([1, 5, 2, 4]);
+    let theirs = SUT::from_iter([4, 2, 1, 6]);
+    let (merged, conflicts) =
+        ours.merge_three_way(&base, &theirs, |_| ConflictResolutionChoice::ChooseFirst);
+    assert_eq!(merged.items(), [4, 2, 1, 5, 6]);
+    assert!(conflicts.is_empty());
+    assert_eq!(merged.index_of_id(&6), Some(4));
+}
+
+#[test]
+fn merge_three_way_conflicts() {
+    let base = Users::from_iter([User::blob(), User::blob_jr(), User::blob_sr()]);
+    let ours = Users::from_iter([
+        User::new(1, "Blob, ours"),
+        User::new(2, "Blob, Jr., ours"),
+        User::new(4, "Blob, III"),
+    ]);
+    let theirs = Users::from_iter([
+        User::new(1, "Blob, theirs"),
+        User::new(3, "Blob, Sr., theirs"),
+        User::new(4, "Blob, the third"),
+    ]);
+    let mut resolved = Vec::new();
+    let (merged, conflicts) = ours.merge_three_way(&base, &theirs, |conflict| {
+        resolved.push(conflict.id);
+        if conflict.id == 1 {
+            ConflictResolutionChoice::ChooseLast
+        } else {
+            ConflictResolutionChoice::ChooseFirst
+        }
+    });
+    assert_eq!(resolved, [1, 2, 3, 4]);
+    assert_eq!(
+        conflicts.iter().map(|c| c.id).collect::<Vec<_>>(),
+        [1, 2, 3, 4]
+    );
+    assert_eq!(conflicts[1].theirs, None);
+    assert_eq!(conflicts[2].ours, None);
+    assert_eq!(conflicts[3].base, None);
+    assert_eq!(
+        merged.items(),
+        [
+            User::new(1, "Blob, theirs"),
+            User::new(2, "Blob, Jr., ours"),
+            User::new(4, "Blob, III")
+        ]
+    );
+}
+
+#[test]
+fn from_iter_merge_unique_with() {
+    let merged = Users::from_iter_merge_unique_with(
+        [User::blob(), User::blob_jr(), User::new(1, "Bob")],
+        |conflict| {
+            conflict
+                .existing
+                .name
+                .borrow_mut()
+                .push_str(&format!(" & {}", conflict.new.name.borrow()));
+            conflict.existing
+        },
+    );
+    assert_eq!(
+        merged.items(),
+        [User::new(1, "Blob & Bob"), User::blob_jr()]
+    );
+
+    let failure = SUT::try_from_iter_merge_unique_with([1, 2, 1], |conflict| Err(conflict.index));
+    assert_eq!(failure, Err(2));
+}
+
+#[test]
+#[should_panic(expected = "The merged element must match the identity of the original")]
+fn from_iter_merge_unique_with_changing_id_panics() {
+    _ = SUT::from_iter_merge_unique_with([1, 1], |conflict| conflict.existing + conflict.new);
+}
+
+#[test]
+fn append_other_merging_panicking_keeps_existing_element() {
+    let mut sut = SUT::from_iter([1, 2, 3]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        sut.append_other_merging([4, 2], |_, _| panic!("merge failed"))
+    }));
+    assert!(result.is_err());
+    assert_eq!(sut.items(), [1, 2, 3, 4]);
+    assert_eq!(sut.index_of_id(&4), Some(3));
+    assert_eq!(sut.get(&2), Some(&2));
+}
+
+#[test]
+#[should_panic(expected = "The id of an element must not be mutated")]
+fn append_other_merging_changing_id_panics() {
+    let mut sut = SUT::from_iter([1, 2, 3]);
+    sut.append_other_merging([2], |existing, new| *existing += new);
+}
+
+#[test]
+fn append_other_merging_and_union_with() {
+    let mut users = Users::from_iter([User::blob(), User::blob_jr()]);
+    users.append_other_merging([User::new(2, "Jr."), User::blob_sr()], |existing, new| {
+        let name = format!("{} {}", existing.name.borrow(), new.name.borrow());
+        *existing.name.borrow_mut() = name;
+    });
+    assert_eq!(
+        users.items(),
+        [User::blob(), User::new(2, "Blob, Jr. Jr."), User::blob_sr()]
+    );
+
+    let union = users.union_with(
+        &Users::from_iter([User::new(1, "Blob!")]),
+        |existing, new| *existing = new,
+    );
+    assert_eq!(union.ids(), [1, 2, 3]);
+    assert_eq!(union.get(&1), Some(&User::new(1, "Blob!")));
+}
+
+#[test]
+fn stateful_conflict_resolvers() {
+    let mut dropped = Vec::new();
+    let users = Users::from_iter_select_unique_with(
+        [User::blob(), User::new(1, "Bob"), User::blob_jr()],
+        |conflict| {
+            dropped.push(conflict.new.clone());
+            ConflictResolutionChoice::ChooseFirst
+        },
+    );
+    assert_eq!(users.items(), [User::blob(), User::blob_jr()]);
+    assert_eq!(dropped, [User::new(1, "Bob")]);
+
+    let mut conflicts = 0;
+    let ints = SUT::try_from_iter_select_unique_ids_with(
+        [1, 2, 1, 2],
+        |e| *e,
+        |_| {
+            conflicts += 1;
+            Ok::<_, ()>(ConflictResolutionChoice::ChooseLast)
+        },
+    );
+    assert_eq!(ints.unwrap().items(), [1, 2]);
+    assert_eq!(conflicts, 2);
+}
+
+#[test]
+fn conflict_context() {
+    let mut conflicts = Vec::new();
+    let users = Users::from_iter_select_unique_with(
+        [
+            User::blob(),
+            User::blob_jr(),
+            User::blob_sr(),
+            User::new(2, "Bob, Jr."),
+        ],
+        |conflict| {
+            conflicts.push((
+                conflict.index,
+                conflict.existing_index,
+                *conflict.id,
+                conflict.existing.clone(),
+                conflict.new.clone(),
+            ));
+            ConflictResolutionChoice::ChooseLast
+        },
+    );
+    assert_eq!(
+        conflicts,
+        [(3, 1, 2, User::blob_jr(), User::new(2, "Bob, Jr."))]
+    );
+    assert_eq!(users.index_of_id(&2), Some(1));
+}
+
+#[test]
+fn deserialize_duplicate_reports_input_index() {
+    assert_eq!(
+        serde_json::from_str::<SUT>("[1, 2, 3, 2]")
+            .unwrap_err()
+            .to_string(),
+        "Duplicate element at offset 3"
+    );
+}
+
+#[test]
+fn serde_identified_vec_with_custom_id_function() {
+    use serde::de::DeserializeSeed;
+    let mut identified_vec =
+        IdentifiedVec::<String, &str, _>::new_identifying_element(|e: &&str| e.to_lowercase());
+    identified_vec.append_other(["Foo", "Bar"]);
+    let json = serde_json::to_string(&identified_vec).unwrap();
+    assert_eq!(json, r#"["Foo","Bar"]"#);
+
+    let seed = IdentifiedVecSeed::<String, String, _>::new(|e: &String| e.to_lowercase());
+    let deserialized = seed
+        .deserialize(&mut serde_json::Deserializer::from_str(&json))
+        .unwrap();
+    assert_eq!(deserialized.ids(), ["foo", "bar"]);
+
+    let seed = IdentifiedVecSeed::<String, String, _>::new(|e: &String| e.to_lowercase());
+    assert_eq!(
+        seed.deserialize(&mut serde_json::Deserializer::from_str(
+            r#"["Foo","Bar","FOO"]"#
+        ))
+        .unwrap_err()
+        .to_string(),
+        "Duplicate element at offset 2"
+    );
+}
+
+#[test]
+fn serde_identified_vec_by_strategy_through_derive() {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Contact {
+        email: String,
+        phone: String,
+    }
+    struct ByEmail;
+    impl IdStrategy<Contact> for ByEmail {
+        type ID = String;
+        fn id(element: &Contact) -> Self::ID {
+            element.email.clone()
+        }
+    }
+    #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+    struct AddressBook {
+        contacts: IdentifiedVecBy<ByEmail, Contact>,
+    }
+
+    let json = r#"{"contacts":[{"email":"blob@example.com","phone":"1"},{"email":"jr@example.com","phone":"2"}]}"#;
+    let address_book = serde_json::from_str::<AddressBook>(json).unwrap();
+    assert_eq!(
+        address_book.contacts.ids(),
+        ["blob@example.com", "jr@example.com"]
+    );
+    assert_eq!(serde_json::to_string(&address_book).unwrap(), json);
+    assert!(serde_json::from_str::<AddressBook>(
+        r#"{"contacts":[{"email":"a","phone":"1"},{"email":"a","phone":"2"}]}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn identified_vec_by_strategies() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Contact {
+        email: &'static str,
+        phone: &'static str,
+    }
+    struct ByEmail;
+    impl IdStrategy<Contact> for ByEmail {
+        type ID = &'static str;
+        fn id(element: &Contact) -> Self::ID {
+            element.email
+        }
+    }
+    struct ByPhone;
+    impl IdStrategy<Contact> for ByPhone {
+        type ID = &'static str;
+        fn id(element: &Contact) -> Self::ID {
+            element.phone
+        }
+    }
+    let blob = Contact {
+        email: "blob@example.com",
+        phone: "1",
+    };
+    let blob_jr = Contact {
+        email: "blob.jr@example.com",
+        phone: "1",
+    };
+
+    let mut by_email = IdentifiedVecBy::<ByEmail, Contact>::default();
+    by_email.extend([blob.clone(), blob_jr.clone()]);
+    let by_phone: IdentifiedVecBy<ByPhone, Contact> = by_email.clone().into_iter().collect();
+    assert_eq!(by_email.ids(), ["blob@example.com", "blob.jr@example.com"]);
+    assert_eq!(by_phone.ids(), ["1"]);
+    assert_eq!((&by_phone).into_iter().collect::<Vec<_>>(), [&blob]);
+
+    let users: IdentifiedVecById<User> =
+        IdentifiedVecOf::<User>::from_iter([User::blob(), User::blob_jr()]).into();
+    assert_eq!(users.get(&2), Some(&User::blob_jr()));
+    assert_eq!(
+        IdentifiedVecBy::<ByIdentifiable, User>::from_iter([User::blob_sr()]).items(),
+        [User::blob_sr()]
+    );
+}
+
+#[test]
+fn identified_vec_by_keeps_strategy() {
+    let mut shifted = IdentifiedVecOf::<u32>::new_identifying_element(|e| e / 10);
+    shifted.append_other([10, 11, 20]);
+    assert_eq!(shifted.ids(), [1, 2]);
+
+    let mut by_value: IdentifiedVecById<u32> = shifted.into();
+    assert_eq!(by_value.ids(), [10, 20]);
+
+    assert_eq!(by_value.append(11), (true, 2));
+    assert_eq!(by_value.insert(5, 0), (true, 0));
+    assert_eq!(by_value.update_or_insert(20, 0), (Some(20), 2));
+    assert_eq!(by_value.remove(&10), Some(10));
+    by_value.retain(|e| *e != 11);
+    assert_eq!(by_value.ids(), [5, 20]);
+    *by_value.entry(7).or_insert_with(|| 7) += 0;
+    assert_eq!(by_value.remove_at(0), 5);
+    assert_eq!(by_value.ids(), [20, 7]);
+    by_value.sort();
+    by_value.swap_at(0, 1);
+    assert_eq!(by_value.split_off(1).ids(), [7]);
+    by_value.clear();
+    assert!(by_value.is_empty());
+
+    let ignoring_id_of_element = IdentifiedVecById::<u32>::from_iter_select_unique_ids_with(
+        [10, 11, 20],
+        |e| e / 10,
+        |_| ConflictResolutionChoice::ChooseFirst,
+    );
+    assert_eq!(ignoring_id_of_element.ids(), [10, 11, 20]);
+    let union = ignoring_id_of_element.union(
+        &IdentifiedVecById::<u32>::from_iter([30]),
+        ConflictResolutionChoice::ChooseFirst,
+    );
+    assert_eq!(union.ids(), [10, 11, 20, 30]);
+}
+
+#[test]
+fn serde_ordered_map() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Team {
+        #[serde(with = "identified_vec::serde::ordered_map")]
+        users: IdentifiedVecOf<User>,
+    }
+    let json = r#"{"users":{"2":{"id":2,"name":"Blob, Jr."},"1":{"id":1,"name":"Blob"}}}"#;
+    let team = serde_json::from_str::<Team>(json).unwrap();
+    assert_eq!(team.users.items(), [User::blob_jr(), User::blob()]);
+    assert_eq!(serde_json::to_string(&team).unwrap(), json);
+
+    let error = |json: &str| serde_json::from_str::<Team>(json).unwrap_err().to_string();
+    assert!(
+        error(r#"{"users":{"1":{"id":1,"name":"a"},"1":{"id":1,"name":"b"}}}"#)
+            .starts_with("Duplicate element at offset 1")
+    );
+    assert!(
+        error(r#"{"users":{"1":{"id":1,"name":"a"},"3":{"id":2,"name":"b"}}}"#)
+            .starts_with("Key of element at offset 1 does not match its id")
+    );
+}
+
+#[test]
+fn serde_ordered_map_injecting_id() {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Tag {
+        #[serde(skip)]
+        id: String,
+        color: String,
+    }
+    impl Identifiable for Tag {
+        type ID = String;
+        fn id(&self) -> Self::ID {
+            self.id.clone()
+        }
+    }
+    impl identified_vec::serde::InjectId for Tag {
+        fn inject_id(&mut self, id: Self::ID) {
+            self.id = id
+        }
+    }
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Tags {
+        #[serde(with = "identified_vec::serde::ordered_map_display::injecting_id")]
+        tags: IdentifiedVecOf<Tag>,
+    }
+    let json = r#"{"tags":{"urgent":{"color":"red"},"done":{"color":"green"}}}"#;
+    let tags = serde_json::from_str::<Tags>(json).unwrap();
+    assert_eq!(tags.tags.ids(), ["urgent", "done"]);
+    assert_eq!(tags.tags.get_at_index(0).unwrap().color, "red");
+    assert_eq!(serde_json::to_string(&tags).unwrap(), json);
+    assert!(
+        serde_json::from_str::<Tags>(r#"{"tags":{"a":{"color":"red"},"a":{"color":"red"}}}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn serde_normalized() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct State {
+        #[serde(with = "identified_vec::serde::normalized")]
+        users: IdentifiedVecOf<User>,
+    }
+    let json = r#"{"users":{"ids":[2,1],"entities":{"2":{"id":2,"name":"Blob, Jr."},"1":{"id":1,"name":"Blob"}}}}"#;
+    let state = serde_json::from_str::<State>(json).unwrap();
+    assert_eq!(state.users.items(), [User::blob_jr(), User::blob()]);
+    assert_eq!(serde_json::to_string(&state).unwrap(), json);
+
+    let error = |json: &str| serde_json::from_str::<State>(json).unwrap_err().to_string();
+    assert!(
+        error(r#"{"users":{"ids":[1,1],"entities":{"1":{"id":1,"name":"a"}}}}"#)
+            .starts_with("Duplicate element at offset 1")
+    );
+    assert!(
+        error(r#"{"users":{"ids":[1,2],"entities":{"1":{"id":1,"name":"a"}}}}"#)
+            .starts_with("No entity found for id at offset 1")
+    );
+    assert!(error(
+        r#"{"users":{"ids":[1],"entities":{"1":{"id":1,"name":"a"},"2":{"id":2,"name":"b"}}}}"#
+    )
+    .starts_with("Entity at offset 1 is not referenced by any id"));
+    assert!(
+        error(r#"{"users":{"ids":[1],"entities":{"1":{"id":2,"name":"a"}}}}"#)
+            .starts_with("Key of element at offset 0 does not match its id")
+    );
+}
+
+newtype_identified_vec!(of: User, named: MergedUsers, duplicates: merge);
+
+impl identified_vec::serde::MergeDuplicate for User {
+    fn merge_duplicate(self, duplicate: Self) -> Self {
+        let name = format!("{} & {}", self.name.borrow(), duplicate.name.borrow());
+        User::new(self.id, &name)
+    }
+}
+
+#[test]
+fn serde_merge_changing_id_fails() {
+    #[derive(Debug, Deserialize)]
+    struct Renumbered(u32);
+    impl Identifiable for Renumbered {
+        type ID = u32;
+        fn id(&self) -> Self::ID {
+            self.0
+        }
+    }
+    impl identified_vec::serde::MergeDuplicate for Renumbered {
+        fn merge_duplicate(self, duplicate: Self) -> Self {
+            Renumbered(self.0 + duplicate.0)
+        }
+    }
+    #[derive(Debug, Deserialize)]
+    struct Import {
+        #[serde(deserialize_with = "identified_vec::serde::merge::deserialize")]
+        #[allow(dead_code)]
+        values: IdentifiedVecOf<Renumbered>,
+    }
+    let error = serde_json::from_str::<Import>(r#"{"values":[2,5,2]}"#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Element with id `4` cannot replace element with id `2` at index 0"));
+}
+
+#[test]
+fn serde_duplicate_policies() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Import {
+        #[serde(with = "identified_vec::serde::keep_first")]
+        first: IdentifiedVecOf<User>,
+        #[serde(with = "identified_vec::serde::keep_last")]
+        last: IdentifiedVecOf<User>,
+        #[serde(with = "identified_vec::serde::collect_warnings")]
+        warned: identified_vec::serde::Deduplicated<IdentifiedVecOf<User>>,
+        #[serde(with = "serde_with::As::<identified_vec::serde::Merge>")]
+        merged: IdentifiedVecOf<User>,
+    }
+    let users =
+        r#"[{"id":1,"name":"a"},{"id":2,"name":"b"},{"id":1,"name":"c"},{"id":1,"name":"d"}]"#;
+    let json = format!(r#"{{"first":{users},"last":{users},"warned":{users},"merged":{users}}}"#);
+    let import = serde_json::from_str::<Import>(&json).unwrap();
+    assert_eq!(import.first.items(), [User::new(1, "a"), User::new(2, "b")]);
+    assert_eq!(import.last.items(), [User::new(1, "d"), User::new(2, "b")]);
+    assert_eq!(import.warned.value, import.first);
+    assert_eq!(
+        import.warned.warnings,
+        [
+            IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(2),
+            IdentifiedVecOfSerdeFailure::DuplicateElementsAtIndex(3)
+        ]
+    );
+    assert_eq!(
+        import.merged.items(),
+        [User::new(1, "a & c & d"), User::new(2, "b")]
+    );
+    assert_eq!(
+        serde_json::to_string(&import.warned.value).unwrap(),
+        r#"[{"id":1,"name":"a"},{"id":2,"name":"b"}]"#
+    );
+
+    let merged = serde_json::from_str::<MergedUsers>(users).unwrap();
+    assert_eq!(merged.items(), import.merged.items());
+}
+
+#[test]
+fn std_collection_traits() {
+    fn collect_twice<C>(elements: &[u32]) -> C
+    where
+        C: Default + FromIterator<u32> + Extend<u32>,
+    {
+        let mut collection: C = elements.iter().copied().collect();
+        collection.extend(elements.iter().copied());
+        collection
+    }
+
+    let identified_vec = collect_twice::<IdentifiedVecOf<u32>>(&[3, 1, 3]);
+    assert_eq!(identified_vec, [3, 1]);
+    assert_eq!(identified_vec, vec![3, 1]);
+    assert_eq!(identified_vec, *[3, 1].as_slice());
+    assert_ne!(identified_vec, [1, 3]);
+    assert_eq!(identified_vec[1], 1);
+    assert_eq!(identified_vec[&3], 3);
+    assert_eq!(IdentifiedVecOf::<u32>::default().len(), 0);
+    assert_eq!(IdentifiedVecOf::<u32>::from([3, 1, 3]), identified_vec);
+    assert_eq!(IdentifiedVecOf::<u32>::from(vec![3, 1, 3]), identified_vec);
+
+    let sut = collect_twice::<SUT>(&[3, 1, 3]);
+    assert_eq!(sut, [3, 1]);
+    assert_eq!(sut, vec![3, 1]);
+    assert_eq!(sut[0], 3);
+    assert_eq!(sut[&1], 1);
+    assert_eq!(SUT::from([3, 1]), sut);
+    assert_eq!(SUT::from(vec![3, 1]), sut);
+    assert!(SUT::default().is_empty());
+
+    let users: Users = [User::blob_jr(), User::blob()].into_iter().collect();
+    assert_eq!(users[&1], User::blob());
+}
+
+#[test]
+#[should_panic(expected = "Expected element with id")]
+fn index_by_unknown_id() {
+    let identified_vec = IdentifiedVecOf::<u32>::from([1]);
+    _ = identified_vec[&2];
+}
+
+#[test]
+fn entry() {
+    let mut users = Users::from_iter([User::blob(), User::blob_jr()]);
+    assert!(matches!(users.entry(2), Entry::Occupied(_)));
+    assert_eq!(users.entry(2).index(), Some(1));
+    assert_eq!(users.entry(3).index(), None);
+    assert_eq!(users.entry(3).id(), &3);
+
+    users
+        .entry(1)
+        .and_modify(|u| *u.name.get_mut() = "Blob!".to_string())
+        .or_insert_with(|| panic!("should not insert"));
+    assert_eq!(*users[&1].name.borrow(), "Blob!");
+
+    let inserted = users
+        .entry(3)
+        .and_modify(|_| panic!("should not modify"))
+        .or_insert_at(0, User::blob_sr);
+    assert_eq!(inserted.id, 3);
+    drop(inserted);
+    assert_eq!(users.ids(), &[3, 1, 2]);
+
+    users.entry(4).or_insert_with(|| User::new(4, "Blob IV"));
+    assert_eq!(users.ids(), &[3, 1, 2, 4]);
+
+    match users.entry(1) {
+        Entry::Occupied(entry) => {
+            assert_eq!(entry.index(), 1);
+            assert_eq!(entry.get(), &users_blob_renamed());
+            assert_eq!(entry.remove().id, 1);
+        }
+        Entry::Vacant(_) => panic!("expected occupied"),
+    }
+    assert_eq!(users.ids(), &[3, 2, 4]);
+
+    match users.entry(5) {
+        Entry::Vacant(entry) => {
+            assert_eq!(
+                entry.try_insert_at(0, User::blob()).err(),
+                Some(Error::IdMismatch {
+                    expected: 5,
+                    found: 1,
+                    index: 0
+                })
+            );
+        }
+        Entry::Occupied(_) => panic!("expected vacant"),
+    }
+    match users.entry(5) {
+        Entry::Vacant(entry) => {
+            assert_eq!(
+                entry.try_insert_at(9, User::new(5, "Blob V")).err(),
+                Some(Error::IndexOutOfBounds { index: 9, len: 3 })
+            );
+        }
+        Entry::Occupied(_) => panic!("expected vacant"),
+    }
+    assert_eq!(users.ids(), &[3, 2, 4]);
+
+    let mut numbers = IdentifiedVecOf::<u32>::new();
+    *numbers.entry(7).or_insert_with(|| 7) += 0;
+    assert_eq!(numbers, [7]);
+}
+
+fn users_blob_renamed() -> User {
+    User::new(1, "Blob!")
+}
+
+#[test]
+#[should_panic(expected = "Element with id `1` cannot replace element with id `2`")]
+fn entry_insert_with_wrong_id_panics() {
+    let mut users = Users::new();
+    users.entry(2).or_insert_with(User::blob);
+}